@@ -29,7 +29,7 @@ async fn main() -> Result<(), Error> {
                 DEFAULT_VEC_FIELD,
                 "feature field",
                 DIM,
-            ))
+            )?)
             .build()?;
     client.create_collection(schema.clone(), None).await?;
 
@@ -0,0 +1,225 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::future::join_all;
+use tokio::sync::mpsc::Sender;
+use tokio::time::Instant;
+use tonic::codegen::InterceptedService;
+use tonic::transport::{Channel, Endpoint};
+use tower::discover::Change;
+
+use crate::client::AuthInterceptor;
+use crate::error::{Error, Result};
+use crate::proto::milvus::milvus_service_client::MilvusServiceClient;
+use crate::proto::milvus::CheckHealthRequest;
+use crate::resolver::Resolver;
+use crate::utils::status_to_result;
+
+const BALANCE_CHANNEL_CAPACITY: usize = 64;
+
+/// Health checks skip the layers of the client, they only need the credentials.
+type ProbeClient = MilvusServiceClient<InterceptedService<Channel, AuthInterceptor>>;
+
+/// Turns a resolved url into an endpoint with the TLS settings of the client.
+pub(crate) type Configure = Box<dyn Fn(String) -> Result<Endpoint> + Send + Sync>;
+
+/// Keeps a balanced channel in sync with the endpoints of a resolver,
+/// ejecting the ones failing the health check and adding them back once they pass again.
+struct Balancer {
+    resolver: Arc<dyn Resolver>,
+    configure: Configure,
+    interceptor: AuthInterceptor,
+    timeout: Duration,
+    sender: Sender<Change<String, Endpoint>>,
+    /// A lazily connected client per known endpoint, only used for health checks.
+    probes: HashMap<String, (Endpoint, ProbeClient)>,
+    active: HashSet<String>,
+}
+
+/// Builds a channel balancing requests over all healthy endpoints returned by `resolver`,
+/// the endpoints are checked again every `interval` until the channel is dropped.
+///
+/// Fails if none of the endpoints is healthy, unless `lazy` is set,
+/// in which case requests wait for the first endpoint to become healthy.
+pub(crate) async fn balanced_channel(
+    resolver: Arc<dyn Resolver>,
+    configure: Configure,
+    interceptor: AuthInterceptor,
+    timeout: Duration,
+    interval: Duration,
+    lazy: bool,
+) -> Result<Channel> {
+    let endpoints = resolver.resolve().await?;
+    if endpoints.is_empty() {
+        return Err(Error::InvalidParameter(
+            "endpoints".to_owned(),
+            "no endpoint resolved".to_owned(),
+        ));
+    }
+
+    // Changes are only consumed while the channel is polled,
+    // so the initial endpoints must fit without blocking
+    let (channel, sender) = Channel::balance_channel(endpoints.len().max(BALANCE_CHANNEL_CAPACITY));
+    let mut balancer = Balancer {
+        resolver,
+        configure,
+        interceptor,
+        timeout,
+        sender,
+        probes: HashMap::new(),
+        active: HashSet::new(),
+    };
+
+    if let Err(err) = balancer.update(endpoints).await {
+        if balancer.active.is_empty() && !lazy {
+            return Err(err);
+        }
+    }
+    if balancer.active.is_empty() && !lazy {
+        return Err(Error::Unexpected("no healthy endpoint".to_owned()));
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            if balancer.sender.is_closed() {
+                break;
+            }
+
+            // Keeps the last known endpoints if the resolver fails
+            let endpoints = match balancer.resolver.resolve().await {
+                Ok(endpoints) if !endpoints.is_empty() => endpoints,
+                _ => balancer.probes.keys().cloned().collect(),
+            };
+            if balancer.update(endpoints).await.is_err() && balancer.sender.is_closed() {
+                break;
+            }
+        }
+    });
+
+    Ok(channel)
+}
+
+impl Balancer {
+    /// Probes all `endpoints` concurrently, inserts the healthy ones into the channel
+    /// and removes the unhealthy ones as well as the ones no longer resolved.
+    ///
+    /// The whole round takes at most the health check timeout, however many endpoints are down.
+    ///
+    /// Returns the last error seen, endpoints are still updated after an error.
+    async fn update(&mut self, endpoints: Vec<String>) -> Result<()> {
+        let endpoints: HashSet<String> = endpoints.into_iter().collect();
+        let mut last_err = None;
+
+        let stale: Vec<String> = self
+            .probes
+            .keys()
+            .filter(|url| !endpoints.contains(*url))
+            .cloned()
+            .collect();
+        for url in stale {
+            self.probes.remove(&url);
+            if self.active.remove(&url) {
+                self.send(Change::Remove(url)).await?;
+            }
+        }
+
+        let mut probes = Vec::with_capacity(endpoints.len());
+        for url in endpoints {
+            let (endpoint, probe) = match self.probes.get(&url) {
+                Some(probe) => probe.clone(),
+                None => match (self.configure)(url.clone()) {
+                    Ok(endpoint) => {
+                        let probe = MilvusServiceClient::with_interceptor(
+                            endpoint.connect_lazy(),
+                            self.interceptor.clone(),
+                        );
+                        self.probes
+                            .insert(url.clone(), (endpoint.clone(), probe.clone()));
+                        (endpoint, probe)
+                    }
+                    Err(err) => {
+                        last_err = Some(err);
+                        continue;
+                    }
+                },
+            };
+
+            probes.push((url, endpoint, probe));
+        }
+
+        let deadline = Instant::now() + self.timeout;
+        let results = join_all(
+            probes
+                .iter()
+                .map(|(_, _, probe)| check_health(probe.clone(), deadline)),
+        )
+        .await;
+
+        for ((url, endpoint, _), result) in probes.into_iter().zip(results) {
+            match result {
+                Ok(()) => {
+                    if self.active.insert(url.clone()) {
+                        self.send(Change::Insert(url, endpoint)).await?;
+                    }
+                }
+                Err(err) => {
+                    if self.active.remove(&url) {
+                        self.send(Change::Remove(url)).await?;
+                    }
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        match last_err {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    async fn send(&self, change: Change<String, Endpoint>) -> Result<()> {
+        self.sender
+            .send(change)
+            .await
+            .map_err(|_| Error::Unexpected("balanced channel closed".to_owned()))
+    }
+}
+
+async fn check_health(mut probe: ProbeClient, deadline: Instant) -> Result<()> {
+    let resp = tokio::time::timeout_at(deadline, probe.check_health(CheckHealthRequest {}))
+        .await
+        .map_err(|_| Error::from(tonic::Status::deadline_exceeded("health check timed out")))??
+        .into_inner();
+    status_to_result(&resp.status)?;
+
+    if resp.is_healthy {
+        Ok(())
+    } else {
+        Err(Error::Unexpected(format!(
+            "unhealthy: {}",
+            resp.reasons.join(", ")
+        )))
+    }
+}
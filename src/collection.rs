@@ -21,30 +21,34 @@ use crate::index::{IndexInfo, IndexParams};
 use crate::proto::milvus::{
     CreateCollectionRequest, CreateIndexRequest, DescribeIndexRequest, DropCollectionRequest,
     DropIndexRequest, FlushRequest, GetCompactionStateRequest, GetCompactionStateResponse,
-    HasCollectionRequest, LoadCollectionRequest, ManualCompactionRequest, ManualCompactionResponse,
-    ReleaseCollectionRequest, ShowCollectionsRequest,
+    HasCollectionRequest, ManualCompactionRequest, ManualCompactionResponse,
+    ReleaseCollectionRequest, ShowCollectionsRequest, ShowCollectionsResponse, ShowType,
 };
 use crate::proto::schema::DataType;
+use crate::rpc::with_deadline;
 use crate::schema::CollectionSchema;
 use crate::types::*;
-use crate::utils::status_to_result;
 use crate::value::Value;
 use crate::{
-    client::{AuthInterceptor, Client},
-    options::{CreateCollectionOptions, GetLoadStateOptions, LoadOptions},
+    client::Client,
+    options::{
+        CreateCollectionOptions, GetLoadStateOptions, ListCollectionsOptions, LoadOptions,
+        RenameCollectionOptions,
+    },
     proto::{
         self,
-        common::{ConsistencyLevel, IndexState, MsgBase, MsgType},
-        milvus::{milvus_service_client::MilvusServiceClient, DescribeCollectionRequest},
+        common::{ConsistencyLevel, IndexState, KeyValuePair, MsgBase, MsgType},
+        milvus::DescribeCollectionRequest,
     },
 };
 use prost::bytes::BytesMut;
 use prost::Message;
 use serde_json;
 use std::collections::HashMap;
-use std::time::Duration;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error as ThisError;
-use tonic::{service::interceptor::InterceptedService, transport::Channel};
 
 #[derive(Debug, Clone)]
 pub struct Collection {
@@ -52,60 +56,310 @@ pub struct Collection {
     pub name: String,
     pub auto_id: bool,
     pub num_shards: usize,
-    // pub num_partitions: usize,
+    pub num_partitions: i64,
     pub consistency_level: ConsistencyLevel,
     pub description: String,
     pub fields: Vec<Field>,
-    // pub enable_dynamic_field: bool,
+    pub enable_dynamic_field: bool,
+    pub properties: CollectionProperties,
+    /// The complete schema, which can be passed back to `create_collection`.
+    pub schema: CollectionSchema,
+    /// The hybrid timestamp of the creation.
+    pub created_timestamp: u64,
+    /// The creation time in milliseconds since the epoch.
+    pub created_utc_timestamp: u64,
+    pub aliases: Vec<String>,
+    pub virtual_channel_names: Vec<String>,
+}
+
+impl Collection {
+    /// The options to create a copy of this collection with `create_collection`.
+    pub fn create_options(&self) -> CreateCollectionOptions {
+        CreateCollectionOptions::with_shard_num(self.num_shards as i32)
+            .consistency_level(self.consistency_level)
+            .properties(self.properties.clone())
+    }
+}
+
+const TTL_SECONDS_KEY: &str = "collection.ttl.seconds";
+const MMAP_ENABLED_KEY: &str = "mmap.enabled";
+const REPLICA_NUMBER_KEY: &str = "collection.replica.number";
+const RESOURCE_GROUPS_KEY: &str = "collection.resource_groups";
+
+/// The properties of a collection, set with [`CreateCollectionOptions::properties`]
+/// or [`Client::alter_collection`].
+///
+/// `None` leaves the property unset, or unchanged when altering a collection.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CollectionProperties {
+    /// How long entities live before they are expired.
+    pub ttl_seconds: Option<u64>,
+    /// Whether the collection is memory-mapped instead of fully loaded into memory.
+    pub mmap_enabled: Option<bool>,
+    /// The number of replicas the collection is loaded with by default.
+    pub replica_number: Option<i32>,
+    /// The resource groups the replicas are loaded into by default.
+    pub resource_groups: Option<Vec<String>>,
+    /// Any other property, sent as is.
+    pub extra: HashMap<String, String>,
+}
+
+impl CollectionProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ttl_seconds(mut self, ttl_seconds: u64) -> Self {
+        self.ttl_seconds = Some(ttl_seconds);
+        self
+    }
+
+    pub fn mmap_enabled(mut self, enabled: bool) -> Self {
+        self.mmap_enabled = Some(enabled);
+        self
+    }
+
+    pub fn replica_number(mut self, replica_number: i32) -> Self {
+        self.replica_number = Some(replica_number);
+        self
+    }
+
+    pub fn resource_groups<I, S>(mut self, resource_groups: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.resource_groups = Some(resource_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub(crate) fn into_kv_pairs(self) -> Vec<KeyValuePair> {
+        let mut pairs: Vec<KeyValuePair> = self
+            .extra
+            .into_iter()
+            .map(|(key, value)| KeyValuePair { key, value })
+            .collect();
+        let mut push = |key: &str, value: String| {
+            pairs.push(KeyValuePair {
+                key: key.to_owned(),
+                value,
+            })
+        };
+
+        if let Some(ttl_seconds) = self.ttl_seconds {
+            push(TTL_SECONDS_KEY, ttl_seconds.to_string());
+        }
+        if let Some(enabled) = self.mmap_enabled {
+            push(MMAP_ENABLED_KEY, enabled.to_string());
+        }
+        if let Some(replica_number) = self.replica_number {
+            push(REPLICA_NUMBER_KEY, replica_number.to_string());
+        }
+        if let Some(resource_groups) = self.resource_groups {
+            push(RESOURCE_GROUPS_KEY, resource_groups.join(","));
+        }
+        pairs
+    }
+}
+
+impl From<Vec<KeyValuePair>> for CollectionProperties {
+    /// Values that can't be parsed are kept in `extra`.
+    fn from(pairs: Vec<KeyValuePair>) -> Self {
+        let mut properties = Self::default();
+        for KeyValuePair { key, value } in pairs {
+            match key.as_str() {
+                TTL_SECONDS_KEY if value.parse::<u64>().is_ok() => {
+                    properties.ttl_seconds = value.parse().ok()
+                }
+                MMAP_ENABLED_KEY if value.to_lowercase().parse::<bool>().is_ok() => {
+                    properties.mmap_enabled = value.to_lowercase().parse().ok()
+                }
+                REPLICA_NUMBER_KEY if value.parse::<i32>().is_ok() => {
+                    properties.replica_number = value.parse().ok()
+                }
+                RESOURCE_GROUPS_KEY => {
+                    properties.resource_groups = Some(
+                        value
+                            .split(',')
+                            .filter(|rg| !rg.is_empty())
+                            .map(str::to_owned)
+                            .collect(),
+                    )
+                }
+                _ => {
+                    properties.extra.insert(key, value);
+                }
+            }
+        }
+        properties
+    }
 }
 
+/// A collection listed by [`Client::list_collections_detailed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionInfo {
+    pub name: String,
+    pub id: i64,
+    /// The hybrid timestamp of the creation.
+    pub created_timestamp: u64,
+    /// The creation time in milliseconds since the epoch.
+    pub created_utc_timestamp: u64,
+    /// The percentage of the collection loaded in memory,
+    /// only reported when listing the loaded collections.
+    pub in_memory_percentage: Option<i64>,
+    /// Whether the collection can be queried,
+    /// only reported when listing the loaded collections.
+    pub query_service_available: Option<bool>,
+}
+
+impl CollectionInfo {
+    /// Splits the parallel arrays of the response into one entry per collection,
+    /// the ones the server didn't fill are left to their defaults.
+    // in_memory_percentages is deprecated for GetLoadingProgress, yet still filled by the
+    // server and cheaper than one more call per collection
+    #[allow(deprecated)]
+    fn from_response(res: ShowCollectionsResponse) -> Vec<Self> {
+        res.collection_names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| Self {
+                name,
+                id: res.collection_ids.get(i).copied().unwrap_or_default(),
+                created_timestamp: res.created_timestamps.get(i).copied().unwrap_or_default(),
+                created_utc_timestamp: res
+                    .created_utc_timestamps
+                    .get(i)
+                    .copied()
+                    .unwrap_or_default(),
+                in_memory_percentage: res.in_memory_percentages.get(i).copied(),
+                query_service_available: res.query_service_available.get(i).copied(),
+            })
+            .collect()
+    }
+}
+
+const ROW_COUNT_KEY: &str = "row_count";
+
+/// The statistics of a collection or a partition, as reported by
+/// [`Client::get_collection_stats`] and [`Client::get_partition_stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CollectionStatistics {
+    /// The number of rows in the flushed segments, deleted rows and the rows
+    /// not flushed yet are not accounted for, see [`Client::count`] for the exact count.
+    pub row_count: u64,
+    /// The statistics not listed above.
+    pub extra: HashMap<String, String>,
+}
+
+impl From<Vec<KeyValuePair>> for CollectionStatistics {
+    /// Values that can't be parsed are kept in `extra`.
+    fn from(pairs: Vec<KeyValuePair>) -> Self {
+        let mut stats = Self::default();
+        for KeyValuePair { key, value } in pairs {
+            match (key.as_str(), value.parse::<u64>()) {
+                (ROW_COUNT_KEY, Ok(row_count)) => stats.row_count = row_count,
+                _ => {
+                    stats.extra.insert(key, value);
+                }
+            }
+        }
+        stats
+    }
+}
+
+/// Collections are identified by (database, collection name),
+/// so collections with the same name in different databases don't collide.
+type CollectionKey = (String, String);
+
+fn collection_key(db_name: &str, name: &str) -> CollectionKey {
+    (db_name.to_owned(), name.to_owned())
+}
+
+/// The descriptions of the collections used by a client and the timestamps of its last writes,
+/// shared by all of its clones.
 #[derive(Debug, Clone)]
 pub(crate) struct CollectionCache {
-    collections: dashmap::DashMap<String, Collection>,
-    timestamps: dashmap::DashMap<String, Timestamp>,
-    client: MilvusServiceClient<InterceptedService<Channel, AuthInterceptor>>,
+    collections: Arc<dashmap::DashMap<CollectionKey, (Collection, Instant)>>,
+    timestamps: Arc<dashmap::DashMap<CollectionKey, Timestamp>>,
+    ttl: Option<Duration>,
 }
 
 impl CollectionCache {
-    pub fn new(client: MilvusServiceClient<InterceptedService<Channel, AuthInterceptor>>) -> Self {
+    /// Descriptions are described again once older than `ttl`, they are kept until
+    /// invalidated if it's `None`.
+    pub fn new(ttl: Option<Duration>) -> Self {
         Self {
-            collections: dashmap::DashMap::new(),
-            timestamps: dashmap::DashMap::new(),
-            client: client,
+            collections: Arc::new(dashmap::DashMap::new()),
+            timestamps: Arc::new(dashmap::DashMap::new()),
+            ttl,
         }
     }
 
-    pub async fn get<'a>(&self, name: &str) -> Result<Collection> {
-        if !self.local_exist(name) {
-            let resp = self
-                .client
-                .clone()
-                .describe_collection(DescribeCollectionRequest {
-                    base: Some(MsgBase::new(MsgType::DescribeCollection)),
-                    db_name: "".to_owned(),
-                    collection_name: name.into(),
-                    collection_id: 0,
-                    time_stamp: 0,
-                })
-                .await?
-                .into_inner();
-
-            status_to_result(&resp.status)?;
-            self.collections
-                .insert(name.to_owned(), Collection::from(resp));
+    pub fn get(&self, db_name: &str, name: &str) -> Option<Collection> {
+        let key = collection_key(db_name, name);
+        let (collection, cached_at) = self.collections.get(&key)?.value().clone();
+        match self.ttl {
+            Some(ttl) if cached_at.elapsed() >= ttl => {
+                self.collections.remove(&key);
+                None
+            }
+            _ => Some(collection),
         }
+    }
 
+    pub fn insert(&self, db_name: &str, name: &str, collection: Collection) {
         self.collections
-            .get(name)
-            .map(|v| v.value().clone())
-            .ok_or(SuperError::Collection(Error::CollectionNotFound(
-                name.to_owned(),
-            )))
+            .insert(collection_key(db_name, name), (collection, Instant::now()));
     }
 
-    pub fn update_timestamp(&self, name: &str, timestamp: Timestamp) {
+    /// Forgets the cached description, the next use describes the collection again.
+    pub fn invalidate(&self, db_name: &str, name: &str) {
+        self.collections.remove(&collection_key(db_name, name));
+    }
+
+    /// Forgets the description and the session timestamp of a dropped collection.
+    pub fn remove(&self, db_name: &str, name: &str) {
+        let key = collection_key(db_name, name);
+        self.collections.remove(&key);
+        self.timestamps.remove(&key);
+    }
+
+    /// Moves the cached collection and its session timestamp to the new name,
+    /// a stale entry of the new name is replaced.
+    pub fn rename(&self, db_name: &str, name: &str, new_db_name: &str, new_name: &str) {
+        let new_key = collection_key(new_db_name, new_name);
+        match self.collections.remove(&collection_key(db_name, name)) {
+            Some((_, (mut collection, cached_at))) => {
+                collection.name = new_name.to_owned();
+                self.collections
+                    .insert(new_key.clone(), (collection, cached_at));
+            }
+            None => {
+                self.collections.remove(&new_key);
+            }
+        }
+        match self.timestamps.remove(&collection_key(db_name, name)) {
+            Some((_, timestamp)) => {
+                self.timestamps.insert(new_key, timestamp);
+            }
+            None => {
+                self.timestamps.remove(&new_key);
+            }
+        }
+    }
+
+    pub fn update_timestamp(&self, db_name: &str, name: &str, timestamp: Timestamp) {
         self.timestamps
-            .entry(name.to_owned())
+            .entry(collection_key(db_name, name))
             .and_modify(|t| {
                 if *t < timestamp {
                     *t = timestamp;
@@ -114,29 +368,51 @@ impl CollectionCache {
             .or_insert(timestamp);
     }
 
-    pub fn get_timestamp(&self, name: &str) -> Option<Timestamp> {
-        self.timestamps.get(name).map(|v| v.value().clone())
-    }
-
-    fn local_exist(&self, name: &str) -> bool {
-        self.collections.contains_key(name)
+    pub fn get_timestamp(&self, db_name: &str, name: &str) -> Option<Timestamp> {
+        self.timestamps
+            .get(&collection_key(db_name, name))
+            .map(|v| v.value().clone())
     }
 }
 
-impl From<proto::milvus::DescribeCollectionResponse> for Collection {
-    fn from(value: proto::milvus::DescribeCollectionResponse) -> Self {
-        let schema = value.schema.unwrap();
-        Self {
+impl TryFrom<proto::milvus::DescribeCollectionResponse> for Collection {
+    type Error = SuperError;
+
+    fn try_from(value: proto::milvus::DescribeCollectionResponse) -> Result<Self> {
+        let schema = value.schema.ok_or_else(|| {
+            SuperError::Unexpected(format!(
+                "collection {} has no schema",
+                value.collection_name
+            ))
+        })?;
+        let consistency_level =
+            ConsistencyLevel::from_i32(value.consistency_level).ok_or_else(|| {
+                SuperError::Unexpected(format!(
+                    "unknown consistency level {}",
+                    value.consistency_level
+                ))
+            })?;
+
+        #[allow(deprecated)]
+        let auto_id = schema.auto_id || schema.fields.iter().any(|f| f.auto_id);
+
+        Ok(Self {
             id: value.collection_id,
             name: value.collection_name,
-            auto_id: schema.auto_id,
+            auto_id,
             num_shards: value.shards_num as usize,
-            // num_partitions: value.partitions_num as usize,
-            consistency_level: ConsistencyLevel::from_i32(value.consistency_level).unwrap(),
-            description: schema.description,
-            fields: schema.fields.into_iter().map(|f| Field::from(f)).collect(),
-            // enable_dynamic_field: value.enable_dynamic_field,
-        }
+            num_partitions: value.num_partitions,
+            consistency_level,
+            description: schema.description.clone(),
+            fields: schema.fields.iter().cloned().map(Field::from).collect(),
+            enable_dynamic_field: schema.enable_dynamic_field,
+            properties: value.properties.into(),
+            schema: schema.into(),
+            created_timestamp: value.created_timestamp,
+            created_utc_timestamp: value.created_utc_timestamp,
+            aliases: value.aliases,
+            virtual_channel_names: value.virtual_channel_names,
+        })
     }
 }
 
@@ -173,7 +449,7 @@ pub struct CompactionState {
 impl From<GetCompactionStateResponse> for CompactionState {
     fn from(value: GetCompactionStateResponse) -> Self {
         Self {
-            state: crate::proto::common::CompactionState::from_i32(value.state).unwrap(),
+            state: value.state(),
             executing_plan_num: value.executing_plan_no,
             timeout_plan_num: value.timeout_plan_no,
             completed_plan_num: value.completed_plan_no,
@@ -202,21 +478,21 @@ impl Client {
 
         schema.encode(&mut buf)?;
 
-        let status = self
-            .client
-            .clone()
-            .create_collection(CreateCollectionRequest {
+        self.invoke(
+            CreateCollectionRequest {
                 base: Some(MsgBase::new(MsgType::CreateCollection)),
+                db_name: self.db_name.clone(),
                 collection_name: schema.name.to_string(),
                 schema: buf.to_vec(),
                 shards_num: options.shard_num,
                 consistency_level: options.consistency_level as i32,
+                properties: options.properties.into_kv_pairs(),
                 ..Default::default()
-            })
-            .await?
-            .into_inner();
-
-        status_to_result(&Some(status))
+            },
+            |mut client, req| async move { client.create_collection(req).await },
+        )
+        .await?;
+        Ok(())
     }
 
     /// Drops a collection with the given name.
@@ -232,17 +508,19 @@ impl Client {
     where
         S: Into<String>,
     {
-        status_to_result(&Some(
-            self.client
-                .clone()
-                .drop_collection(DropCollectionRequest {
-                    base: Some(MsgBase::new(MsgType::DropCollection)),
-                    collection_name: name.into(),
-                    ..Default::default()
-                })
-                .await?
-                .into_inner(),
-        ))
+        let name = name.into();
+        self.invoke(
+            DropCollectionRequest {
+                base: Some(MsgBase::new(MsgType::DropCollection)),
+                db_name: self.db_name.clone(),
+                collection_name: name.clone(),
+            },
+            |mut client, req| async move { client.drop_collection(req).await },
+        )
+        .await?;
+
+        self.collection_cache.remove(&self.db_name, &name);
+        Ok(())
     }
 
     /// Retrieves a list of collections.
@@ -252,19 +530,47 @@ impl Client {
     /// A `Result` containing a vector of collection names if successful, or an error if the operation fails.
     pub async fn list_collections(&self) -> Result<Vec<String>> {
         let response = self
-            .client
-            .clone()
-            .show_collections(ShowCollectionsRequest {
-                base: Some(MsgBase::new(MsgType::ShowCollections)),
-                ..Default::default()
-            })
-            .await?
-            .into_inner();
-
-        status_to_result(&response.status)?;
+            .invoke(
+                ShowCollectionsRequest {
+                    base: Some(MsgBase::new(MsgType::ShowCollections)),
+                    db_name: self.db_name.clone(),
+                    ..Default::default()
+                },
+                |mut client, req| async move { client.show_collections(req).await },
+            )
+            .await?;
         Ok(response.collection_names)
     }
 
+    /// Lists the collections with their ids and creation times, or only the ones
+    /// loaded in memory with their load percentages.
+    pub async fn list_collections_detailed(
+        &self,
+        options: Option<ListCollectionsOptions>,
+    ) -> Result<Vec<CollectionInfo>> {
+        let options = options.unwrap_or_default();
+        let (show_type, collection_names) = match options.loaded_only {
+            true => (ShowType::InMemory, options.collection_names),
+            false => (ShowType::All, Vec::new()),
+        };
+
+        // collection_names is deprecated, but still the only way to filter the loaded collections
+        #[allow(deprecated)]
+        let request = ShowCollectionsRequest {
+            base: Some(MsgBase::new(MsgType::ShowCollections)),
+            db_name: self.db_name.clone(),
+            time_stamp: 0,
+            r#type: show_type as _,
+            collection_names,
+        };
+        let response = self
+            .invoke(request, |mut client, req| async move {
+                client.show_collections(req).await
+            })
+            .await?;
+        Ok(CollectionInfo::from_response(response))
+    }
+
     /// Retrieves information about a collection.
     ///
     /// # Arguments
@@ -279,21 +585,56 @@ impl Client {
         S: Into<String>,
     {
         let resp = self
-            .client
-            .clone()
-            .describe_collection(DescribeCollectionRequest {
-                base: Some(MsgBase::new(MsgType::DescribeCollection)),
-                db_name: "".to_owned(),
-                collection_name: name.into(),
-                collection_id: 0,
-                time_stamp: 0,
-            })
-            .await?
-            .into_inner();
+            .invoke(
+                DescribeCollectionRequest {
+                    base: Some(MsgBase::new(MsgType::DescribeCollection)),
+                    db_name: self.db_name.clone(),
+                    collection_name: name.into(),
+                    collection_id: 0,
+                    time_stamp: 0,
+                },
+                |mut client, req| async move { client.describe_collection(req).await },
+            )
+            .await?;
 
-        status_to_result(&resp.status)?;
+        Collection::try_from(resp)
+    }
 
-        Ok(resp.into())
+    /// Describes the collection again and replaces its cached description,
+    /// which is used to compose queries, searches and deletes.
+    ///
+    /// The cache is kept up to date with the changes made through this client,
+    /// this is only needed after changes made by other clients.
+    pub async fn refresh_collection(&self, name: &str) -> Result<Collection> {
+        self.collection_cache.invalidate(&self.db_name, name);
+        let collection = self.describe_collection(name).await?;
+        self.collection_cache
+            .insert(&self.db_name, name, collection.clone());
+        Ok(collection)
+    }
+
+    /// Runs `f` with the cached description of the collection, described on a cache miss,
+    /// and once again with a fresh description if the server reports the collection missing,
+    /// as it may have been dropped and recreated by another client.
+    ///
+    /// The error codes of the server don't tell a schema mismatch apart from other invalid
+    /// requests, so a schema changed by another client needs [`Client::refresh_collection`].
+    pub(crate) async fn with_collection<T, F, Fut>(&self, name: &str, f: F) -> Result<T>
+    where
+        F: Fn(Collection) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let collection = match self.collection_cache.get(&self.db_name, name) {
+            Some(collection) => collection,
+            None => return f(self.refresh_collection(name).await?).await,
+        };
+
+        match f(collection).await {
+            Err(SuperError::CollectionNotExists(..)) => {
+                f(self.refresh_collection(name).await?).await
+            }
+            result => result,
+        }
     }
 
     /// Checks if a collection with the given name exists.
@@ -311,77 +652,123 @@ impl Client {
     {
         let name = name.into();
         let res = self
-            .client
-            .clone()
-            .has_collection(HasCollectionRequest {
-                base: Some(MsgBase::new(MsgType::HasCollection)),
-                db_name: "".to_string(),
-                collection_name: name.clone(),
-                time_stamp: 0,
-            })
-            .await?
-            .into_inner();
-
-        status_to_result(&res.status)?;
+            .invoke(
+                HasCollectionRequest {
+                    base: Some(MsgBase::new(MsgType::HasCollection)),
+                    db_name: self.db_name.clone(),
+                    collection_name: name.clone(),
+                    time_stamp: 0,
+                },
+                |mut client, req| async move { client.has_collection(req).await },
+            )
+            .await?;
 
         Ok(res.value)
     }
 
-    // todo(yah01): implement this after the refactor done
-    // pub async fn rename_collection<S>(&self, name: S, new_name: S) -> Result<()>
-    // where
-    //     S: Into<String>,
-    // {
-    //     let name = name.into();
-    //     let new_name = new_name.into();
-    //     let res = self
-    //         .client
-    //         .clone()
-    //         .rename_collection(proto::milvus::RenameCollectionRequest {
-    //             base: Some(MsgBase::new(MsgType::RenameCollection)),
-    //             collection_name: name.clone(),
-    //             new_collection_name: new_name.clone(),
-    //         })
-    //         .await?
-    //         .into_inner();
-
-    //     status_to_result(&Some(res))?;
-
-    //     Ok(())
-    // }
+    /// Renames a collection, optionally moving it to another database.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The current name of the collection.
+    /// * `new_name` - The new name of the collection.
+    /// * `options` - The target database, the current one by default.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success or failure.
+    pub async fn rename_collection(
+        &self,
+        name: impl Into<String>,
+        new_name: impl Into<String>,
+        options: Option<RenameCollectionOptions>,
+    ) -> Result<()> {
+        let name = name.into();
+        let new_name = new_name.into();
+        let new_db_name = options
+            .unwrap_or_default()
+            .new_db_name
+            .unwrap_or_else(|| self.db_name.clone());
+
+        self.invoke(
+            proto::milvus::RenameCollectionRequest {
+                base: Some(MsgBase::new(MsgType::RenameCollection)),
+                db_name: self.db_name.clone(),
+                old_name: name.clone(),
+                new_name: new_name.clone(),
+                new_db_name: new_db_name.clone(),
+            },
+            |mut client, req| async move { client.rename_collection(req).await },
+        )
+        .await?;
 
-    /// Retrieves the statistics of a collection.
+        self.collection_cache
+            .rename(&self.db_name, &name, &new_db_name, &new_name);
+        Ok(())
+    }
+
+    /// Sets the given properties of a collection, the ones left to `None` are unchanged.
     ///
     /// # Arguments
     ///
     /// * `name` - The name of the collection.
+    /// * `properties` - The properties to set.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `HashMap` with string keys and string values representing the collection statistics.
-    pub async fn get_collection_stats(&self, name: &str) -> Result<HashMap<String, String>> {
-        let res = self
-            .client
-            .clone()
-            .get_collection_statistics(proto::milvus::GetCollectionStatisticsRequest {
-                base: Some(MsgBase::new(MsgType::GetCollectionStatistics)),
-                db_name: "".into(),
-                collection_name: name.to_owned(),
-            })
-            .await?
-            .into_inner();
+    /// Returns a `Result` indicating success or failure.
+    pub async fn alter_collection<S>(&self, name: S, properties: CollectionProperties) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let name = name.into();
+        self.invoke(
+            proto::milvus::AlterCollectionRequest {
+                base: Some(MsgBase::new(MsgType::AlterCollection)),
+                db_name: self.db_name.clone(),
+                collection_name: name.clone(),
+                collection_id: 0,
+                properties: properties.into_kv_pairs(),
+            },
+            |mut client, req| async move { client.alter_collection(req).await },
+        )
+        .await?;
 
-        status_to_result(&res.status)?;
+        self.collection_cache.invalidate(&self.db_name, &name);
+        Ok(())
+    }
 
-        Ok(res.stats.into_iter().map(|s| (s.key, s.value)).collect())
+    /// Retrieves the statistics of a collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the collection.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `CollectionStatistics` of the collection.
+    pub async fn get_collection_stats(&self, name: &str) -> Result<CollectionStatistics> {
+        let res = self
+            .invoke(
+                proto::milvus::GetCollectionStatisticsRequest {
+                    base: Some(MsgBase::new(MsgType::GetCollectionStatistics)),
+                    db_name: self.db_name.clone(),
+                    collection_name: name.to_owned(),
+                },
+                |mut client, req| async move { client.get_collection_statistics(req).await },
+            )
+            .await?;
+
+        Ok(res.stats.into())
     }
 
-    /// Loads a collection with the given name and options.
+    /// Loads a collection with the given name and options, and waits until it's loaded,
+    /// see [`Client::load_collection_async`] to not wait.
     ///
     /// # Arguments
     ///
     /// * `collection_name` - The name of the collection to load.
-    /// * `options` - Optional load options.
+    /// * `options` - Optional load options, with the deadline of the whole load.
     ///
     /// # Returns
     ///
@@ -395,35 +782,16 @@ impl Client {
         S: Into<String>,
     {
         let options = options.unwrap_or_default();
-        let collection_name = collection_name.into();
-        status_to_result(&Some(
-            self.client
-                .clone()
-                .load_collection(LoadCollectionRequest {
-                    base: Some(MsgBase::new(MsgType::LoadCollection)),
-                    db_name: "".to_string(),
-                    collection_name: collection_name.clone(),
-                    replica_number: options.replica_number,
-                    resource_groups: vec![],
-                    refresh: false,
-                })
+        let load = async {
+            self.load_collection_async(collection_name, Some(options))
                 .await?
-                .into_inner(),
-        ))?;
-
-        loop {
-            match self.get_load_state(&collection_name, None).await? {
-                proto::common::LoadState::NotExist => {
-                    return Err(SuperError::Unexpected("collection not found".to_owned()))
-                }
-                proto::common::LoadState::Loading => (),
-                proto::common::LoadState::Loaded => return Ok(()),
-                proto::common::LoadState::NotLoad => {
-                    return Err(SuperError::Unexpected("collection not loaded".to_owned()))
-                }
-            }
+                .wait(None)
+                .await
+        };
 
-            tokio::time::sleep(Duration::from_millis(config::WAIT_LOAD_DURATION_MS)).await;
+        match options.timeout {
+            Some(timeout) => with_deadline(timeout, load).await,
+            None => self.with_call_deadline(load).await,
         }
     }
 
@@ -451,18 +819,16 @@ impl Client {
     {
         let options = options.unwrap_or_default();
         let res = self
-            .client
-            .clone()
-            .get_load_state(proto::milvus::GetLoadStateRequest {
-                base: Some(MsgBase::new(MsgType::Undefined)),
-                db_name: "".into(),
-                collection_name: collection_name.into(),
-                partition_names: options.partition_names,
-            })
-            .await?
-            .into_inner();
-
-        status_to_result(&res.status)?;
+            .invoke(
+                proto::milvus::GetLoadStateRequest {
+                    base: Some(MsgBase::new(MsgType::Undefined)),
+                    db_name: self.db_name.clone(),
+                    collection_name: collection_name.into(),
+                    partition_names: options.partition_names,
+                },
+                |mut client, req| async move { client.get_load_state(req).await },
+            )
+            .await?;
 
         Ok(res.state())
     }
@@ -480,35 +846,31 @@ impl Client {
     where
         S: Into<String>,
     {
-        status_to_result(&Some(
-            self.client
-                .clone()
-                .release_collection(ReleaseCollectionRequest {
-                    base: Some(MsgBase::new(MsgType::ReleaseCollection)),
-                    db_name: "".to_string(),
-                    collection_name: collection_name.into(),
-                })
-                .await?
-                .into_inner(),
-        ))
+        self.invoke(
+            ReleaseCollectionRequest {
+                base: Some(MsgBase::new(MsgType::ReleaseCollection)),
+                db_name: self.db_name.clone(),
+                collection_name: collection_name.into(),
+            },
+            |mut client, req| async move { client.release_collection(req).await },
+        )
+        .await?;
+        Ok(())
     }
 
     pub async fn flush<S>(&self, collection_name: S) -> Result<()>
     where
         S: Into<String>,
     {
-        let res = self
-            .client
-            .clone()
-            .flush(FlushRequest {
+        self.invoke(
+            FlushRequest {
                 base: Some(MsgBase::new(MsgType::Flush)),
-                db_name: "".to_string(),
+                db_name: self.db_name.clone(),
                 collection_names: vec![collection_name.into()],
-            })
-            .await?
-            .into_inner();
-
-        status_to_result(&res.status)?;
+            },
+            |mut client, req| async move { client.flush(req).await },
+        )
+        .await?;
 
         Ok(())
     }
@@ -523,20 +885,19 @@ impl Client {
         S: Into<String>,
     {
         let field_name = field_name.into();
-        let status = self
-            .client
-            .clone()
-            .create_index(CreateIndexRequest {
+        self.invoke(
+            CreateIndexRequest {
                 base: Some(MsgBase::new(MsgType::CreateIndex)),
-                db_name: "".to_string(),
+                db_name: self.db_name.clone(),
                 collection_name: collection_name.into(),
                 field_name,
                 extra_params: index_params.extra_kv_params(),
                 index_name: index_params.name().clone(),
-            })
-            .await?
-            .into_inner();
-        status_to_result(&Some(status))
+            },
+            |mut client, req| async move { client.create_index(req).await },
+        )
+        .await?;
+        Ok(())
     }
 
     pub async fn create_index<S>(
@@ -550,34 +911,36 @@ impl Client {
     {
         let collection_name = collection_name.into();
         let field_name = field_name.into();
-        self.create_index_impl(
-            collection_name.clone(),
-            field_name.clone(),
-            index_params.clone(),
-        )
-        .await?;
-
-        loop {
-            let index_infos = self
-                .describe_index(collection_name.clone(), field_name.clone())
-                .await?;
-
-            let index_info = index_infos
-                .iter()
-                .find(|&x| x.params().name() == index_params.name());
-            if index_info.is_none() {
-                return Err(SuperError::Unexpected(
-                    "failed to describe index".to_owned(),
-                ));
+        self.with_call_deadline(async {
+            self.create_index_impl(
+                collection_name.clone(),
+                field_name.clone(),
+                index_params.clone(),
+            )
+            .await?;
+
+            loop {
+                let index_infos = self
+                    .describe_index(collection_name.clone(), field_name.clone())
+                    .await?;
+
+                let index_info = index_infos
+                    .iter()
+                    .find(|&x| x.params().name() == index_params.name())
+                    .ok_or_else(|| SuperError::Unexpected("failed to describe index".to_owned()))?;
+                match index_info.state() {
+                    IndexState::Finished => return Ok(()),
+                    IndexState::Failed => {
+                        return Err(SuperError::Collection(Error::IndexBuildFailed))
+                    }
+                    _ => (),
+                };
+
+                tokio::time::sleep(Duration::from_millis(config::WAIT_CREATE_INDEX_DURATION_MS))
+                    .await;
             }
-            match index_info.unwrap().state() {
-                IndexState::Finished => return Ok(()),
-                IndexState::Failed => return Err(SuperError::Collection(Error::IndexBuildFailed)),
-                _ => (),
-            };
-
-            tokio::time::sleep(Duration::from_millis(config::WAIT_CREATE_INDEX_DURATION_MS)).await;
-        }
+        })
+        .await
     }
 
     pub async fn describe_index<S>(
@@ -589,69 +952,69 @@ impl Client {
         S: Into<String>,
     {
         let res = self
-            .client
-            .clone()
-            .describe_index(DescribeIndexRequest {
-                base: Some(MsgBase::new(MsgType::DescribeIndex)),
-                db_name: "".to_string(),
-                collection_name: collection_name.into(),
-                field_name: field_name.into(),
-                index_name: "".to_string(),
-                timestamp: 0,
-            })
-            .await?
-            .into_inner();
-        status_to_result(&res.status)?;
-
-        Ok(res.index_descriptions.into_iter().map(Into::into).collect())
+            .invoke(
+                DescribeIndexRequest {
+                    base: Some(MsgBase::new(MsgType::DescribeIndex)),
+                    db_name: self.db_name.clone(),
+                    collection_name: collection_name.into(),
+                    field_name: field_name.into(),
+                    index_name: "".to_string(),
+                    timestamp: 0,
+                },
+                |mut client, req| async move { client.describe_index(req).await },
+            )
+            .await?;
+
+        res.index_descriptions
+            .into_iter()
+            .map(IndexInfo::try_from)
+            .collect()
     }
 
     pub async fn drop_index<S>(&self, collection_name: S, field_name: S) -> Result<()>
     where
         S: Into<String>,
     {
-        let status = self
-            .client
-            .clone()
-            .drop_index(DropIndexRequest {
+        self.invoke(
+            DropIndexRequest {
                 base: Some(MsgBase::new(MsgType::DropIndex)),
-                db_name: "".to_string(),
+                db_name: self.db_name.clone(),
                 collection_name: collection_name.into(),
                 field_name: field_name.into(),
                 index_name: "".to_string(),
-            })
-            .await?
-            .into_inner();
-        status_to_result(&Some(status))
+            },
+            |mut client, req| async move { client.drop_index(req).await },
+        )
+        .await?;
+        Ok(())
     }
 
     pub async fn manual_compaction<S>(&self, collection_name: S) -> Result<CompactionInfo>
     where
         S: Into<String>,
     {
-        let collection = self.collection_cache.get(&collection_name.into()).await?;
-
         let resp = self
-            .client
-            .clone()
-            .manual_compaction(ManualCompactionRequest {
-                collection_id: collection.id,
-                timetravel: 0,
+            .with_collection(&collection_name.into(), |collection| async move {
+                self.invoke(
+                    ManualCompactionRequest {
+                        collection_id: collection.id,
+                        timetravel: 0,
+                    },
+                    |mut client, req| async move { client.manual_compaction(req).await },
+                )
+                .await
             })
-            .await?
-            .into_inner();
-        status_to_result(&resp.status)?;
+            .await?;
         Ok(resp.into())
     }
 
     pub async fn get_compaction_state(&self, compaction_id: i64) -> Result<CompactionState> {
         let resp = self
-            .client
-            .clone()
-            .get_compaction_state(GetCompactionStateRequest { compaction_id })
-            .await?
-            .into_inner();
-        status_to_result(&resp.status)?;
+            .invoke(
+                GetCompactionStateRequest { compaction_id },
+                |mut client, req| async move { client.get_compaction_state(req).await },
+            )
+            .await?;
         Ok(resp.into())
     }
 }
@@ -686,3 +1049,106 @@ pub enum Error {
     #[error("index build failed")]
     IndexBuildFailed,
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{
+        Collection, CollectionCache, CollectionInfo, CollectionProperties, CollectionStatistics,
+    };
+    use crate::proto::common::KeyValuePair;
+    use crate::proto::milvus::{DescribeCollectionResponse, ShowCollectionsResponse};
+
+    #[test]
+    fn test_collection_properties() {
+        let properties = CollectionProperties::new()
+            .ttl_seconds(3600)
+            .mmap_enabled(true)
+            .replica_number(2)
+            .resource_groups(["rg1", "rg2"])
+            .property("partitionkey.isolation", "true");
+
+        let pairs = properties.clone().into_kv_pairs();
+        assert_eq!(5, pairs.len());
+        assert!(pairs
+            .iter()
+            .any(|kv| kv.key == "collection.ttl.seconds" && kv.value == "3600"));
+        assert_eq!(properties, CollectionProperties::from(pairs));
+
+        let properties = CollectionProperties::from(
+            CollectionProperties::new()
+                .property("mmap.enabled", "True")
+                .property("collection.ttl.seconds", "forever")
+                .into_kv_pairs(),
+        );
+        assert_eq!(Some(true), properties.mmap_enabled);
+        assert_eq!(None, properties.ttl_seconds);
+        assert_eq!("forever", properties.extra["collection.ttl.seconds"]);
+    }
+
+    #[test]
+    fn test_collection_statistics() {
+        let pair = |key: &str, value: &str| KeyValuePair {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        };
+
+        let stats = CollectionStatistics::from(vec![pair("row_count", "2000")]);
+        assert_eq!(2000, stats.row_count);
+        assert!(stats.extra.is_empty());
+
+        let stats = CollectionStatistics::from(vec![pair("row_count", "n/a"), pair("size", "1")]);
+        assert_eq!(0, stats.row_count);
+        assert_eq!("n/a", stats.extra["row_count"]);
+        assert_eq!("1", stats.extra["size"]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_collection_info_from_response() {
+        let infos = CollectionInfo::from_response(ShowCollectionsResponse {
+            collection_names: vec!["book".to_owned(), "film".to_owned()],
+            collection_ids: vec![1, 2],
+            created_utc_timestamps: vec![1700000000000, 1700000001000],
+            in_memory_percentages: vec![100],
+            ..Default::default()
+        });
+
+        assert_eq!(2, infos.len());
+        assert_eq!("film", infos[1].name);
+        assert_eq!(2, infos[1].id);
+        assert_eq!(1700000001000, infos[1].created_utc_timestamp);
+        assert_eq!(0, infos[1].created_timestamp);
+        assert_eq!(Some(100), infos[0].in_memory_percentage);
+        assert_eq!(None, infos[1].in_memory_percentage);
+        assert_eq!(None, infos[0].query_service_available);
+    }
+
+    fn collection(id: i64) -> Collection {
+        Collection::try_from(DescribeCollectionResponse {
+            collection_id: id,
+            collection_name: "book".to_owned(),
+            schema: Some(Default::default()),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_collection_cache_ttl() {
+        let cache = CollectionCache::new(Some(Duration::from_millis(50)));
+        cache.insert("default", "book", collection(1));
+        assert_eq!(1, cache.get("default", "book").unwrap().id);
+        assert!(cache.get("films", "book").is_none());
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(cache.get("default", "book").is_none());
+
+        let cache = CollectionCache::new(None);
+        cache.insert("default", "book", collection(1));
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(1, cache.get("default", "book").unwrap().id);
+        cache.invalidate("default", "book");
+        assert!(cache.get("default", "book").is_none());
+    }
+}
@@ -19,3 +19,10 @@ use core::time;
 pub const WAIT_LOAD_DURATION_MS: u64 = 500;
 pub const WAIT_CREATE_INDEX_DURATION_MS: u64 = 100;
 pub const RPC_TIMEOUT: time::Duration = time::Duration::new(10, 0);
+pub const RETRY_MAX_ATTEMPTS: u32 = 3;
+pub const RETRY_BASE_BACKOFF: time::Duration = time::Duration::from_millis(100);
+pub const RETRY_MAX_BACKOFF: time::Duration = time::Duration::from_secs(3);
+pub const HEALTH_CHECK_INTERVAL: time::Duration = time::Duration::from_secs(10);
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+pub const IDENTIFIER_HEADER: &str = "identifier";
+pub const DEFAULT_URI: &str = "http://localhost:19530";
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use crate::{
+    error::{Error, Result},
     proto::schema::{
         self, field_data::Field, scalar_field::Data as ScalarData,
         vector_field::Data as VectorData, DataType, ScalarField, VectorField,
@@ -49,25 +50,30 @@ pub struct FieldColumn {
     pub is_dynamic: bool,
 }
 
-impl From<schema::FieldData> for FieldColumn {
-    fn from(fd: schema::FieldData) -> Self {
+impl TryFrom<schema::FieldData> for FieldColumn {
+    type Error = Error;
+
+    fn try_from(fd: schema::FieldData) -> Result<Self> {
         let (dim, max_length) = fd
             .field
             .as_ref()
             .map(get_dim_max_length)
             .unwrap_or((Some(1), None));
 
-        let value: ValueVec = fd.field.map(Into::into).unwrap_or(ValueVec::None);
+        let value = match fd.field {
+            Some(field) => ValueVec::try_from(field)?,
+            None => ValueVec::None,
+        };
         let dtype = DataType::from_i32(fd.r#type).unwrap_or(DataType::None);
 
-        FieldColumn {
+        Ok(FieldColumn {
             name: fd.field_name,
             dtype,
-            dim: dim.unwrap(),
+            dim: dim.unwrap_or(1),
             max_length: max_length.unwrap_or(0),
             value,
             is_dynamic: fd.is_dynamic,
-        }
+        })
     }
 }
 
@@ -113,7 +119,8 @@ impl FieldColumn {
         })
     }
 
-    pub fn push(&mut self, val: Value) {
+    /// Appends a value, fails if its type doesn't match the type of the column.
+    pub fn push(&mut self, val: Value) -> Result<()> {
         match (&mut self.value, val) {
             (ValueVec::None, Value::None) => (),
             (ValueVec::Bool(vec), Value::Bool(i)) => vec.push(i),
@@ -126,8 +133,18 @@ impl FieldColumn {
             (ValueVec::String(vec), Value::String(i)) => vec.push(i.to_string()),
             (ValueVec::Binary(vec), Value::Binary(i)) => vec.extend_from_slice(i.as_ref()),
             (ValueVec::Float(vec), Value::FloatArray(i)) => vec.extend_from_slice(i.as_ref()),
-            _ => panic!("column type mismatch"),
+            (_, val) => {
+                return Err(Error::InvalidParameter(
+                    self.name.clone(),
+                    format!(
+                        "a value of type {:?} in a column of type {:?}",
+                        val.data_type(),
+                        self.dtype
+                    ),
+                ))
+            }
         }
+        Ok(())
     }
 
     #[inline]
@@ -158,55 +175,64 @@ impl FieldColumn {
     }
 }
 
-impl From<FieldColumn> for schema::FieldData {
-    fn from(this: FieldColumn) -> schema::FieldData {
-        schema::FieldData {
-            field_name: this.name.to_string(),
-            field_id: 0,
-            r#type: this.dtype as _,
-            field: Some(match this.value {
-                ValueVec::None => Field::Scalars(ScalarField { data: None }),
-                ValueVec::Bool(v) => Field::Scalars(ScalarField {
-                    data: Some(ScalarData::BoolData(schema::BoolArray { data: v })),
-                }),
-                ValueVec::Int(v) => Field::Scalars(ScalarField {
-                    data: Some(ScalarData::IntData(schema::IntArray { data: v })),
-                }),
-                ValueVec::Long(v) => Field::Scalars(ScalarField {
-                    data: Some(ScalarData::LongData(schema::LongArray { data: v })),
-                }),
-                ValueVec::Float(v) => match this.dtype {
-                    DataType::Float => Field::Scalars(ScalarField {
-                        data: Some(ScalarData::FloatData(schema::FloatArray { data: v })),
-                    }),
-                    DataType::FloatVector => Field::Vectors(VectorField {
-                        data: Some(VectorData::FloatVector(schema::FloatArray { data: v })),
-                        dim: this.dim,
-                    }),
-                    _ => unimplemented!(),
-                },
-                ValueVec::Double(v) => Field::Scalars(ScalarField {
-                    data: Some(ScalarData::DoubleData(schema::DoubleArray { data: v })),
-                }),
-                ValueVec::String(v) => Field::Scalars(ScalarField {
-                    data: Some(ScalarData::StringData(schema::StringArray { data: v })),
-                }),
-                ValueVec::Json(v) => Field::Scalars(ScalarField {
-                    data: Some(ScalarData::JsonData(schema::JsonArray { data: v })),
-                }),
-                ValueVec::Array(v) => Field::Scalars(ScalarField {
-                    data: Some(ScalarData::ArrayData(schema::ArrayArray {
-                        data: v,
-                        element_type: this.dtype as _,
-                    })),
+impl TryFrom<FieldColumn> for schema::FieldData {
+    type Error = Error;
+
+    fn try_from(this: FieldColumn) -> Result<schema::FieldData> {
+        let field = match this.value {
+            ValueVec::None => Field::Scalars(ScalarField { data: None }),
+            ValueVec::Bool(v) => Field::Scalars(ScalarField {
+                data: Some(ScalarData::BoolData(schema::BoolArray { data: v })),
+            }),
+            ValueVec::Int(v) => Field::Scalars(ScalarField {
+                data: Some(ScalarData::IntData(schema::IntArray { data: v })),
+            }),
+            ValueVec::Long(v) => Field::Scalars(ScalarField {
+                data: Some(ScalarData::LongData(schema::LongArray { data: v })),
+            }),
+            ValueVec::Float(v) => match this.dtype {
+                DataType::Float => Field::Scalars(ScalarField {
+                    data: Some(ScalarData::FloatData(schema::FloatArray { data: v })),
                 }),
-                ValueVec::Binary(v) => Field::Vectors(VectorField {
-                    data: Some(VectorData::BinaryVector(v)),
+                DataType::FloatVector => Field::Vectors(VectorField {
+                    data: Some(VectorData::FloatVector(schema::FloatArray { data: v })),
                     dim: this.dim,
                 }),
+                dtype => {
+                    return Err(Error::InvalidParameter(
+                        this.name,
+                        format!("float values in a column of type {:?}", dtype),
+                    ))
+                }
+            },
+            ValueVec::Double(v) => Field::Scalars(ScalarField {
+                data: Some(ScalarData::DoubleData(schema::DoubleArray { data: v })),
+            }),
+            ValueVec::String(v) => Field::Scalars(ScalarField {
+                data: Some(ScalarData::StringData(schema::StringArray { data: v })),
+            }),
+            ValueVec::Json(v) => Field::Scalars(ScalarField {
+                data: Some(ScalarData::JsonData(schema::JsonArray { data: v })),
             }),
+            ValueVec::Array(v) => Field::Scalars(ScalarField {
+                data: Some(ScalarData::ArrayData(schema::ArrayArray {
+                    data: v,
+                    element_type: this.dtype as _,
+                })),
+            }),
+            ValueVec::Binary(v) => Field::Vectors(VectorField {
+                data: Some(VectorData::BinaryVector(v)),
+                dim: this.dim,
+            }),
+        };
+
+        Ok(schema::FieldData {
+            field_name: this.name,
+            field_id: 0,
+            r#type: this.dtype as _,
+            field: Some(field),
             is_dynamic: false,
-        }
+        })
     }
 }
 
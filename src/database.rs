@@ -0,0 +1,86 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::Result;
+use crate::{
+    client::Client,
+    proto::{
+        common::{MsgBase, MsgType},
+        milvus::{CreateDatabaseRequest, DropDatabaseRequest, ListDatabasesRequest},
+    },
+};
+
+impl Client {
+    /// Creates a database.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_name` - The name of the database to create.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success or failure.
+    pub async fn create_database(&self, db_name: impl Into<String>) -> Result<()> {
+        self.invoke(
+            CreateDatabaseRequest {
+                base: Some(MsgBase::new(MsgType::CreateDatabase)),
+                db_name: db_name.into(),
+            },
+            |mut client, req| async move { client.create_database(req).await },
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Drops a database, the database must not contain any collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_name` - The name of the database to drop.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success or failure.
+    pub async fn drop_database(&self, db_name: impl Into<String>) -> Result<()> {
+        self.invoke(
+            DropDatabaseRequest {
+                base: Some(MsgBase::new(MsgType::DropDatabase)),
+                db_name: db_name.into(),
+            },
+            |mut client, req| async move { client.drop_database(req).await },
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Retrieves the names of all databases.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of database names if successful, or an error if the operation fails.
+    pub async fn list_databases(&self) -> Result<Vec<String>> {
+        let res = self
+            .invoke(
+                ListDatabasesRequest {
+                    base: Some(MsgBase::new(MsgType::ListDatabases)),
+                },
+                |mut client, req| async move { client.list_databases(req).await },
+            )
+            .await?;
+
+        Ok(res.db_names)
+    }
+}
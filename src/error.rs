@@ -32,8 +32,9 @@ pub enum Error {
     #[error("{0:?}")]
     Collection(#[from] CollectionError),
 
+    /// Boxed as the status is much larger than the other variants.
     #[error("{0:?}")]
-    Grpc(#[from] GrpcError),
+    Grpc(Box<GrpcError>),
 
     #[error("{0:?}")]
     Schema(#[from] SchemaError),
@@ -41,6 +42,28 @@ pub enum Error {
     #[error("{0:?} {1:?}")]
     Server(ErrorCode, String),
 
+    #[error("collection not exists: {1}")]
+    CollectionNotExists(ErrorCode, String),
+
+    #[error("rate limited: {0}")]
+    RateLimited(String),
+
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("index not exist: {0}")]
+    IndexNotExist(String),
+
+    #[error("server not ready: {1}")]
+    NotReady(ErrorCode, String),
+
+    #[error("disk quota exhausted: {0}")]
+    DiskQuotaExhausted(String),
+
+    /// Any other error the server marked as retriable.
+    #[error("server unavailable: {0:?} {1:?}")]
+    Unavailable(ErrorCode, String),
+
     #[error("{0:?}")]
     ProstEncode(#[from] prost::EncodeError),
 
@@ -52,6 +75,9 @@ pub enum Error {
     #[error("{0:?}")]
     SerdeJsonErr(#[from] serde_json::Error),
 
+    #[error("{0:?}")]
+    Io(#[from] std::io::Error),
+
     #[error("parameter {0:?} with invalid value {1:?}")]
     InvalidParameter(String, String),
 
@@ -60,12 +86,194 @@ pub enum Error {
 
     #[error("{0}")]
     Unexpected(String),
+
+    #[error("client is closed")]
+    Closed,
+}
+
+impl Error {
+    /// The error code returned by the server, `None` if the error happened in the client
+    /// or the transport.
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            Error::Server(code, _)
+            | Error::CollectionNotExists(code, _)
+            | Error::NotReady(code, _)
+            | Error::Unavailable(code, _) => Some(*code),
+            Error::RateLimited(_) => Some(ErrorCode::RateLimit),
+            Error::PermissionDenied(_) => Some(ErrorCode::PermissionDenied),
+            Error::IndexNotExist(_) => Some(ErrorCode::IndexNotExist),
+            Error::DiskQuotaExhausted(_) => Some(ErrorCode::DiskQuotaExhausted),
+            _ => None,
+        }
+    }
+
+    /// Whether sending the same request again may succeed:
+    /// unavailable servers, rate limiting, servers not ready to serve
+    /// and errors the server marked as retriable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Grpc(status) => status.code() == tonic::Code::Unavailable,
+            Error::RateLimited(_) | Error::NotReady(..) | Error::Unavailable(..) => true,
+            _ => false,
+        }
+    }
+
+    /// A short name of what went wrong, fit for a metric label:
+    /// the `ErrorCode` of server errors, the gRPC code of transport errors, `ClientError` otherwise.
+    pub fn code_name(&self) -> String {
+        match (self, self.code()) {
+            (_, Some(code)) => code.as_str_name().to_owned(),
+            (Error::Grpc(status), _) => format!("{:?}", status.code()),
+            _ => "ClientError".to_owned(),
+        }
+    }
+}
+
+/// Maps the `code` of the statuses of Milvus 2.3+ to the deprecated `ErrorCode`,
+/// for the errors with a typed variant.
+fn legacy_code(code: i32) -> Option<ErrorCode> {
+    match code {
+        1 => Some(ErrorCode::NotReadyServe),
+        7 => Some(ErrorCode::DiskQuotaExhausted),
+        8 => Some(ErrorCode::RateLimit),
+        100 => Some(ErrorCode::CollectionNotExists),
+        700 => Some(ErrorCode::IndexNotExist),
+        1401 => Some(ErrorCode::PermissionDenied),
+        _ => None,
+    }
+}
+
+impl From<GrpcError> for Error {
+    fn from(status: GrpcError) -> Self {
+        Error::Grpc(Box::new(status))
+    }
 }
 
 impl From<Status> for Error {
+    #[allow(deprecated)] // error_code is still set by all servers, code only by Milvus 2.3+
     fn from(s: Status) -> Self {
-        Error::Server(ErrorCode::from_i32(s.error_code).unwrap(), s.reason)
+        let mut reason = s.reason;
+        let code = match (ErrorCode::from_i32(s.error_code), legacy_code(s.code)) {
+            (Some(ErrorCode::Success | ErrorCode::UnexpectedError) | None, Some(code)) => code,
+            // Only the code reports the error, and it has no typed variant
+            (Some(ErrorCode::Success), None) if s.code != 0 => {
+                reason = format!("error code {}: {}", s.code, reason);
+                ErrorCode::UnexpectedError
+            }
+            (Some(code), _) => code,
+            (None, None) => {
+                reason = format!("unknown error code {}: {}", s.error_code, reason);
+                ErrorCode::UnexpectedError
+            }
+        };
+
+        match code {
+            ErrorCode::CollectionNotExists | ErrorCode::CollectionNameNotFound => {
+                Error::CollectionNotExists(code, reason)
+            }
+            ErrorCode::RateLimit => Error::RateLimited(reason),
+            ErrorCode::PermissionDenied => Error::PermissionDenied(reason),
+            ErrorCode::IndexNotExist => Error::IndexNotExist(reason),
+            ErrorCode::NotReadyServe | ErrorCode::NotReadyCoordActivating => {
+                Error::NotReady(code, reason)
+            }
+            ErrorCode::DiskQuotaExhausted => Error::DiskQuotaExhausted(reason),
+            code if s.retriable => Error::Unavailable(code, reason),
+            code => Error::Server(code, reason),
+        }
     }
 }
 
 pub type Result<T> = result::Result<T, Error>;
+
+#[cfg(test)]
+mod test {
+    use super::Error;
+    use crate::proto::common::{ErrorCode, Status};
+
+    #[allow(deprecated)]
+    fn status(error_code: i32, reason: &str) -> Status {
+        Status {
+            error_code,
+            reason: reason.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_status() {
+        let err = Error::from(status(ErrorCode::CollectionNotExists as i32, "book"));
+        assert!(matches!(&err, Error::CollectionNotExists(_, reason) if reason == "book"));
+        assert_eq!(Some(ErrorCode::CollectionNotExists), err.code());
+        assert!(!err.is_retryable());
+
+        let err = Error::from(status(ErrorCode::CollectionNameNotFound as i32, "book"));
+        assert!(matches!(err, Error::CollectionNotExists(..)));
+        assert_eq!(Some(ErrorCode::CollectionNameNotFound), err.code());
+
+        let err = Error::from(status(ErrorCode::RateLimit as i32, "too fast"));
+        assert!(matches!(err, Error::RateLimited(_)));
+        assert!(err.is_retryable());
+        assert_eq!("RateLimit", err.code_name());
+
+        let err = Error::from(status(ErrorCode::NotReadyCoordActivating as i32, ""));
+        assert!(matches!(err, Error::NotReady(..)));
+        assert_eq!(Some(ErrorCode::NotReadyCoordActivating), err.code());
+        assert!(err.is_retryable());
+
+        let err = Error::from(status(ErrorCode::IllegalArgument as i32, "dim"));
+        assert!(matches!(err, Error::Server(ErrorCode::IllegalArgument, _)));
+
+        // Codes unknown to this version of the SDK don't panic
+        let err = Error::from(status(12345, "new"));
+        assert_eq!(Some(ErrorCode::UnexpectedError), err.code());
+        assert!(err.to_string().contains("12345"));
+
+        let err = Error::from(Status {
+            retriable: true,
+            ..status(12345, "new")
+        });
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn test_from_status_code() {
+        // Milvus 2.3+ servers set code, and may leave error_code unset
+        let err = Error::from(Status {
+            code: 100,
+            ..status(ErrorCode::Success as i32, "book")
+        });
+        assert!(matches!(err, Error::CollectionNotExists(..)));
+
+        let err = Error::from(Status {
+            code: 8,
+            ..status(ErrorCode::UnexpectedError as i32, "too fast")
+        });
+        assert!(matches!(err, Error::RateLimited(_)));
+
+        // error_code takes precedence when set
+        let err = Error::from(Status {
+            code: 100,
+            ..status(ErrorCode::IllegalArgument as i32, "dim")
+        });
+        assert!(matches!(err, Error::Server(ErrorCode::IllegalArgument, _)));
+
+        let err = Error::from(Status {
+            code: 3,
+            retriable: true,
+            ..status(ErrorCode::UnexpectedError as i32, "memory limit exceeded")
+        });
+        assert!(matches!(
+            err,
+            Error::Unavailable(ErrorCode::UnexpectedError, _)
+        ));
+        assert!(err.is_retryable());
+
+        let err = Error::from(Status {
+            retriable: false,
+            ..status(ErrorCode::UnexpectedError as i32, "")
+        });
+        assert!(!err.is_retryable());
+    }
+}
@@ -0,0 +1,140 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::proto::{
+    self,
+    milvus::{CheckHealthRequest, GetComponentStatesRequest, GetVersionRequest},
+};
+
+pub use crate::proto::common::StateCode;
+pub use crate::proto::milvus::QuotaState;
+
+/// The result of a health check of the whole cluster.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    pub is_healthy: bool,
+    /// Why the cluster is unhealthy, empty if it's healthy.
+    pub reasons: Vec<String>,
+    /// The limits currently applied to reads and writes because of quotas.
+    pub quota_states: Vec<QuotaState>,
+}
+
+impl From<proto::milvus::CheckHealthResponse> for HealthReport {
+    fn from(value: proto::milvus::CheckHealthResponse) -> Self {
+        Self {
+            is_healthy: value.is_healthy,
+            reasons: value.reasons,
+            quota_states: value
+                .quota_states
+                .into_iter()
+                .map(|s| QuotaState::from_i32(s).unwrap_or(QuotaState::Unknown))
+                .collect(),
+        }
+    }
+}
+
+/// The state of a single component, such as the proxy the client is connected to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentState {
+    pub node_id: i64,
+    pub role: String,
+    /// A state code unknown to the SDK is reported as [`StateCode::Abnormal`].
+    pub state_code: StateCode,
+    pub extra_info: HashMap<String, String>,
+}
+
+impl From<proto::milvus::ComponentInfo> for ComponentState {
+    fn from(value: proto::milvus::ComponentInfo) -> Self {
+        Self {
+            node_id: value.node_id,
+            role: value.role,
+            state_code: StateCode::from_i32(value.state_code).unwrap_or(StateCode::Abnormal),
+            extra_info: value
+                .extra_info
+                .into_iter()
+                .map(|kv| (kv.key, kv.value))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentStates {
+    pub state: Option<ComponentState>,
+    pub subcomponent_states: Vec<ComponentState>,
+}
+
+impl ComponentStates {
+    /// Whether the component and all of its subcomponents are healthy.
+    pub fn is_healthy(&self) -> bool {
+        self.state
+            .iter()
+            .chain(&self.subcomponent_states)
+            .all(|s| s.state_code == StateCode::Healthy)
+    }
+}
+
+impl From<proto::milvus::ComponentStates> for ComponentStates {
+    fn from(value: proto::milvus::ComponentStates) -> Self {
+        Self {
+            state: value.state.map(Into::into),
+            subcomponent_states: value
+                .subcomponent_states
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl Client {
+    /// Checks the health of the cluster, quota limits included.
+    pub async fn check_health(&self) -> Result<HealthReport> {
+        let res = self
+            .invoke(CheckHealthRequest {}, |mut client, req| async move {
+                client.check_health(req).await
+            })
+            .await?;
+
+        Ok(res.into())
+    }
+
+    /// Returns the version of the server, e.g. `v2.3.4`.
+    pub async fn get_version(&self) -> Result<String> {
+        let res = self
+            .invoke(GetVersionRequest {}, |mut client, req| async move {
+                client.get_version(req).await
+            })
+            .await?;
+
+        Ok(res.version)
+    }
+
+    /// Returns the state of the proxy the request is sent to.
+    pub async fn get_component_states(&self) -> Result<ComponentStates> {
+        let res = self
+            .invoke(GetComponentStatesRequest {}, |mut client, req| async move {
+                client.get_component_states(req).await
+            })
+            .await?;
+
+        Ok(res.into())
+    }
+}
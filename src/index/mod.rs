@@ -1,5 +1,6 @@
 use strum_macros::{Display, EnumString};
 
+use crate::error::Error;
 use crate::proto::{
     common::{IndexState, KeyValuePair},
     milvus::IndexDescription,
@@ -137,18 +138,29 @@ impl IndexInfo {
     }
 }
 
-impl From<IndexDescription> for IndexInfo {
-    fn from(description: IndexDescription) -> Self {
+impl TryFrom<IndexDescription> for IndexInfo {
+    type Error = Error;
+
+    fn try_from(description: IndexDescription) -> Result<Self, Self::Error> {
         let mut params: HashMap<String, String> = HashMap::from_iter(
             description
                 .params
                 .iter()
                 .map(|kv| (kv.key.clone(), kv.value.clone())),
         );
-
-        let index_type = IndexType::from_str(&params.remove("index_type").unwrap()).unwrap();
-        let metric_type = MetricType::from_str(&params.remove("metric_type").unwrap()).unwrap();
-        let params = serde_json::from_str(params.get("params").unwrap()).unwrap();
+        let mut take = |key: &str| {
+            params.remove(key).ok_or_else(|| {
+                Error::Unexpected(format!("index {} has no {}", description.index_name, key))
+            })
+        };
+
+        let index_type = take("index_type")?;
+        let index_type = IndexType::from_str(&index_type)
+            .map_err(|_| Error::Unexpected(format!("unknown index type {}", index_type)))?;
+        let metric_type = take("metric_type")?;
+        let metric_type = MetricType::from_str(&metric_type)
+            .map_err(|_| Error::Unexpected(format!("unknown metric type {}", metric_type)))?;
+        let params = serde_json::from_str(&take("params")?)?;
 
         let params = IndexParams::new(
             description.index_name.clone(),
@@ -156,11 +168,11 @@ impl From<IndexDescription> for IndexInfo {
             metric_type,
             params,
         );
-        Self {
+        Ok(Self {
             field_name: description.field_name.clone(),
             id: description.index_id,
             params: params,
             state: description.state(),
-        }
+        })
     }
 }
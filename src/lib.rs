@@ -17,16 +17,30 @@
 pub mod client;
 pub mod collection;
 pub mod data;
+pub mod database;
 pub mod error;
+pub mod health;
+pub mod metrics;
 pub mod mutate;
 pub mod options;
 pub mod partition;
 pub mod query;
+pub mod recorder;
+pub mod resolver;
+pub mod retry;
 pub mod schema;
+pub mod server;
+pub mod service;
 pub mod value;
 
+mod balance;
 mod config;
 pub mod index;
+pub mod load;
 pub mod proto;
+mod rpc;
+#[cfg(feature = "tracing")]
+mod trace;
 pub mod types;
+mod uri;
 mod utils;
@@ -0,0 +1,156 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use futures_util::{stream, Stream};
+
+use crate::client::Client;
+use crate::config;
+use crate::error::{Error, Result};
+use crate::options::{GetLoadStateOptions, LoadOptions};
+use crate::proto::common::{LoadState, MsgBase, MsgType};
+use crate::proto::milvus::{GetLoadingProgressRequest, LoadCollectionRequest};
+use crate::rpc::with_deadline;
+
+/// A collection being loaded, returned by [`Client::load_collection_async`].
+///
+/// Dropping the handle doesn't cancel the load.
+#[derive(Debug, Clone)]
+pub struct LoadHandle {
+    client: Client,
+    collection_name: String,
+}
+
+impl LoadHandle {
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// The percentage of the collection loaded, from 0 to 100.
+    pub async fn progress(&self) -> Result<i64> {
+        self.client
+            .get_loading_progress(&self.collection_name, None)
+            .await
+    }
+
+    /// Polls the progress until the collection is loaded, the stream ends after
+    /// yielding 100 or an error.
+    pub fn progress_stream(&self) -> impl Stream<Item = Result<i64>> + '_ {
+        // The state is whether to wait before polling, `None` once the stream is over
+        stream::unfold(Some(false), move |state| async move {
+            if state? {
+                tokio::time::sleep(Duration::from_millis(config::WAIT_LOAD_DURATION_MS)).await;
+            }
+
+            let progress = self.progress().await;
+            let next = match progress {
+                Ok(progress) if progress < 100 => Some(true),
+                _ => None,
+            };
+            Some((progress, next))
+        })
+    }
+
+    /// Waits until the collection is loaded, or at most `timeout` if any.
+    ///
+    /// Fails if the collection is dropped or released in the meantime.
+    pub async fn wait(&self, timeout: Option<Duration>) -> Result<()> {
+        match timeout {
+            Some(timeout) => with_deadline(timeout, self.wait_loaded()).await,
+            None => self.wait_loaded().await,
+        }
+    }
+
+    async fn wait_loaded(&self) -> Result<()> {
+        loop {
+            match self
+                .client
+                .get_load_state(&self.collection_name, None)
+                .await?
+            {
+                LoadState::NotExist => {
+                    return Err(Error::Unexpected("collection not found".to_owned()))
+                }
+                LoadState::Loading => (),
+                LoadState::Loaded => return Ok(()),
+                LoadState::NotLoad => {
+                    return Err(Error::Unexpected("collection not loaded".to_owned()))
+                }
+            }
+
+            tokio::time::sleep(Duration::from_millis(config::WAIT_LOAD_DURATION_MS)).await;
+        }
+    }
+}
+
+impl Client {
+    /// Starts loading a collection and returns without waiting for the load to complete,
+    /// use the returned handle to follow its progress.
+    pub async fn load_collection_async<S>(
+        &self,
+        collection_name: S,
+        options: Option<LoadOptions>,
+    ) -> Result<LoadHandle>
+    where
+        S: Into<String>,
+    {
+        let options = options.unwrap_or_default();
+        let collection_name = collection_name.into();
+        self.invoke(
+            LoadCollectionRequest {
+                base: Some(MsgBase::new(MsgType::LoadCollection)),
+                db_name: self.db_name.clone(),
+                collection_name: collection_name.clone(),
+                replica_number: options.replica_number,
+                resource_groups: vec![],
+                refresh: false,
+            },
+            |mut client, req| async move { client.load_collection(req).await },
+        )
+        .await?;
+
+        Ok(LoadHandle {
+            client: self.clone(),
+            collection_name,
+        })
+    }
+
+    /// Returns the percentage of the collection, or of the given partitions, loaded in memory.
+    pub async fn get_loading_progress<S>(
+        &self,
+        collection_name: S,
+        options: Option<GetLoadStateOptions>,
+    ) -> Result<i64>
+    where
+        S: Into<String>,
+    {
+        let options = options.unwrap_or_default();
+        let res = self
+            .invoke(
+                GetLoadingProgressRequest {
+                    base: Some(MsgBase::new(MsgType::Undefined)),
+                    collection_name: collection_name.into(),
+                    partition_names: options.partition_names,
+                    db_name: self.db_name.clone(),
+                },
+                |mut client, req| async move { client.get_loading_progress(req).await },
+            )
+            .await?;
+
+        Ok(res.progress)
+    }
+}
@@ -0,0 +1,213 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::proto::{
+    common::{MsgBase, MsgType},
+    milvus::GetMetricsRequest,
+};
+
+/// The kind of metrics to ask the server for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MetricsRequestType {
+    /// The topology of the cluster, with the hardware, configuration and quotas of every node.
+    SystemInfo,
+}
+
+impl MetricsRequestType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MetricsRequestType::SystemInfo => "system_info",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Metrics {
+    SystemInfo(SystemTopology),
+}
+
+/// The nodes of the cluster and how they are connected.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct SystemTopology {
+    pub nodes_info: Vec<TopologyNode>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct TopologyNode {
+    pub identifier: i64,
+    pub connected: Vec<ConnectionEdge>,
+    pub infos: NodeInfos,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ConnectionEdge {
+    pub connected_identifier: i64,
+    #[serde(rename = "type")]
+    pub connection_type: String,
+    pub target_type: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct NodeInfos {
+    pub has_error: bool,
+    pub error_reason: String,
+    pub name: String,
+    /// The role of the node, e.g. `proxy`, `querynode` or `datacoord`.
+    #[serde(rename = "type")]
+    pub role: String,
+    pub id: i64,
+    pub created_time: String,
+    pub updated_time: String,
+    pub hardware_infos: HardwareMetrics,
+    pub system_info: DeployMetrics,
+    /// The configuration of the node, its keys depend on the role.
+    pub system_configurations: serde_json::Value,
+    /// Only reported by proxies.
+    pub quota_metrics: Option<QuotaMetrics>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct HardwareMetrics {
+    pub ip: String,
+    pub cpu_core_count: i64,
+    pub cpu_core_usage: f64,
+    /// Total memory in bytes.
+    pub memory: u64,
+    /// Used memory in bytes.
+    pub memory_usage: u64,
+    /// Total disk in bytes.
+    pub disk: f64,
+    /// Used disk in bytes.
+    pub disk_usage: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DeployMetrics {
+    pub system_version: String,
+    pub deploy_mode: String,
+    pub build_version: String,
+    pub build_time: String,
+    pub used_go_version: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct QuotaMetrics {
+    #[serde(rename = "Hms")]
+    pub hardware: HardwareMetrics,
+    #[serde(rename = "Rms")]
+    pub rates: Vec<RateMetric>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct RateMetric {
+    /// What is measured, e.g. `InsertRate` or `NQ`.
+    #[serde(rename = "Label")]
+    pub label: String,
+    /// Per second.
+    #[serde(rename = "Rate")]
+    pub rate: f64,
+}
+
+impl Client {
+    /// Returns the metrics of the given kind, collected by the server from the whole cluster.
+    pub async fn get_metrics(&self, request_type: MetricsRequestType) -> Result<Metrics> {
+        let request = serde_json::json!({ "metric_type": request_type.as_str() });
+        let res = self
+            .invoke(
+                GetMetricsRequest {
+                    base: Some(MsgBase::new(MsgType::SystemInfo)),
+                    request: request.to_string(),
+                },
+                |mut client, req| async move { client.get_metrics(req).await },
+            )
+            .await?;
+
+        match request_type {
+            MetricsRequestType::SystemInfo => {
+                Ok(Metrics::SystemInfo(serde_json::from_str(&res.response)?))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SystemTopology;
+
+    #[test]
+    fn test_parse_system_topology() {
+        let response = r#"{
+            "nodes_info": [{
+                "identifier": 7,
+                "connected": [{"connected_identifier": 3, "type": "forward", "target_type": "querycoord"}],
+                "infos": {
+                    "has_error": false,
+                    "error_reason": "",
+                    "name": "proxy7",
+                    "hardware_infos": {
+                        "ip": "10.0.0.7:19529",
+                        "cpu_core_count": 8,
+                        "cpu_core_usage": 12.5,
+                        "memory": 16777216,
+                        "memory_usage": 4194304,
+                        "disk": 104857600,
+                        "disk_usage": 2097152
+                    },
+                    "system_info": {"system_version": "v2.3.4", "deploy_mode": "CLUSTER"},
+                    "created_time": "2024-01-02 03:04:05",
+                    "updated_time": "2024-01-02 03:04:05",
+                    "type": "proxy",
+                    "id": 7,
+                    "system_configurations": {"SimdType": "auto"},
+                    "quota_metrics": {
+                        "Hms": {"cpu_core_count": 8},
+                        "Rms": [{"Label": "InsertRate", "Rate": 1024.0}]
+                    }
+                }
+            }]
+        }"#;
+
+        let topology: SystemTopology = serde_json::from_str(response).unwrap();
+        assert_eq!(1, topology.nodes_info.len());
+
+        let node = &topology.nodes_info[0];
+        assert_eq!(7, node.identifier);
+        assert_eq!("querycoord", node.connected[0].target_type);
+        assert_eq!("proxy", node.infos.role);
+        assert_eq!(8, node.infos.hardware_infos.cpu_core_count);
+        assert_eq!(4194304, node.infos.hardware_infos.memory_usage);
+        assert_eq!("v2.3.4", node.infos.system_info.system_version);
+        assert_eq!("auto", node.infos.system_configurations["SimdType"]);
+
+        let quota = node.infos.quota_metrics.as_ref().unwrap();
+        assert_eq!("InsertRate", quota.rates[0].label);
+        assert_eq!(1024.0, quota.rates[0].rate);
+    }
+}
@@ -3,7 +3,7 @@ use prost::bytes::{BufMut, BytesMut};
 use crate::error::Result;
 use crate::{
     client::Client,
-    collection,
+    collection::{self, Collection},
     data::FieldColumn,
     error::Error,
     proto::{
@@ -13,7 +13,6 @@ use crate::{
         schema::{scalar_field::Data, DataType},
     },
     schema::FieldData,
-    utils::status_to_result,
     value::ValueVec,
 };
 
@@ -94,22 +93,25 @@ impl Client {
         let collection_name = collection_name.into();
 
         let result = self
-            .client
-            .clone()
-            .insert(InsertRequest {
-                base: Some(MsgBase::new(MsgType::Insert)),
-                db_name: "".to_string(),
-                collection_name: collection_name.clone(),
-                partition_name: options.partition_name,
-                num_rows: row_num as u32,
-                fields_data: fields_data.into_iter().map(|f| f.into()).collect(),
-                hash_keys: Vec::new(),
-            })
-            .await?
-            .into_inner();
+            .invoke(
+                InsertRequest {
+                    base: Some(MsgBase::new(MsgType::Insert)),
+                    db_name: self.db_name.clone(),
+                    collection_name: collection_name.clone(),
+                    partition_name: options.partition_name,
+                    num_rows: row_num as u32,
+                    fields_data: fields_data
+                        .into_iter()
+                        .map(FieldData::try_from)
+                        .collect::<Result<_>>()?,
+                    hash_keys: Vec::new(),
+                },
+                |mut client, req| async move { client.insert(req).await },
+            )
+            .await?;
 
         self.collection_cache
-            .update_timestamp(&collection_name, result.timestamp);
+            .update_timestamp(&self.db_name, &collection_name, result.timestamp);
 
         Ok(result)
     }
@@ -121,73 +123,42 @@ impl Client {
     ) -> Result<crate::proto::milvus::MutationResult> {
         let collection_name = collection_name.into();
 
-        let expr = self.compose_expr(&collection_name, options).await?;
-
-        let result = self
-            .client
-            .clone()
-            .delete(proto::milvus::DeleteRequest {
-                base: Some(MsgBase::new(MsgType::Delete)),
-                db_name: "".to_string(),
-                collection_name: collection_name.clone(),
-                expr: expr,
-                partition_name: options.partition_name.clone(),
-                hash_keys: Vec::new(),
+        let result = if options.filter.is_empty() {
+            let collection_name = &collection_name;
+            self.with_collection(collection_name, |collection| async move {
+                let expr = compose_expr(&collection, &options.ids)?;
+                self.delete_by_expr(collection_name, expr, options).await
             })
             .await?
-            .into_inner();
+        } else {
+            self.delete_by_expr(&collection_name, options.filter.clone(), options)
+                .await?
+        };
 
         self.collection_cache
-            .update_timestamp(&collection_name, result.timestamp);
+            .update_timestamp(&self.db_name, &collection_name, result.timestamp);
 
         Ok(result)
     }
 
-    async fn compose_expr(&self, collection_name: &str, options: &DeleteOptions) -> Result<String> {
-        let mut expr = String::new();
-        match options.filter.len() {
-            0 => {
-                let collection = self.collection_cache.get(collection_name).await?;
-                let pk = collection.fields.iter().find(|f| f.is_primary_key).unwrap();
-
-                let mut expr = String::new();
-                expr.push_str(&pk.name);
-                expr.push_str(" in [");
-                match (pk.dtype, options.ids.clone()) {
-                    (DataType::Int64, ValueVec::Long(values)) => {
-                        for (i, v) in values.iter().enumerate() {
-                            if i > 0 {
-                                expr.push_str(",");
-                            }
-                            expr.push_str(format!("{}", v).as_str());
-                        }
-                        expr
-                    }
-
-                    (DataType::VarChar, ValueVec::String(values)) => {
-                        for (i, v) in values.iter().enumerate() {
-                            if i > 0 {
-                                expr.push_str(",");
-                            }
-                            expr.push_str(v.as_str());
-                        }
-                        expr
-                    }
-
-                    _ => {
-                        return Err(Error::InvalidParameter(
-                            "pk type".to_owned(),
-                            pk.dtype.as_str_name().to_owned(),
-                        ));
-                    }
-                }
-            }
-
-            _ => options.filter.clone(),
-        };
-        expr.push(')');
-
-        Ok(expr)
+    async fn delete_by_expr(
+        &self,
+        collection_name: &str,
+        expr: String,
+        options: &DeleteOptions,
+    ) -> Result<crate::proto::milvus::MutationResult> {
+        self.invoke(
+            proto::milvus::DeleteRequest {
+                base: Some(MsgBase::new(MsgType::Delete)),
+                db_name: self.db_name.clone(),
+                collection_name: collection_name.to_owned(),
+                expr: expr,
+                partition_name: options.partition_name.clone(),
+                hash_keys: Vec::new(),
+            },
+            |mut client, req| async move { client.delete(req).await },
+        )
+        .await
     }
 
     pub async fn upsert<S>(
@@ -204,23 +175,77 @@ impl Client {
         let collection_name = collection_name.into();
 
         let result = self
-            .client
-            .clone()
-            .upsert(UpsertRequest {
-                base: Some(MsgBase::new(MsgType::Upsert)),
-                db_name: "".to_string(),
-                collection_name: collection_name.clone(),
-                partition_name: options.partition_name,
-                num_rows: row_num as u32,
-                fields_data: fields_data.into_iter().map(|f| f.into()).collect(),
-                hash_keys: Vec::new(),
-            })
-            .await?
-            .into_inner();
+            .invoke(
+                UpsertRequest {
+                    base: Some(MsgBase::new(MsgType::Upsert)),
+                    db_name: self.db_name.clone(),
+                    collection_name: collection_name.clone(),
+                    partition_name: options.partition_name,
+                    num_rows: row_num as u32,
+                    fields_data: fields_data
+                        .into_iter()
+                        .map(FieldData::try_from)
+                        .collect::<Result<_>>()?,
+                    hash_keys: Vec::new(),
+                },
+                |mut client, req| async move { client.upsert(req).await },
+            )
+            .await?;
 
         self.collection_cache
-            .update_timestamp(&collection_name, result.timestamp);
+            .update_timestamp(&self.db_name, &collection_name, result.timestamp);
 
         Ok(result)
     }
 }
+
+/// Composes the expression selecting the rows of the given primary keys.
+fn compose_expr(collection: &Collection, ids: &ValueVec) -> Result<String> {
+    let pk = collection
+        .fields
+        .iter()
+        .find(|f| f.is_primary_key)
+        .ok_or(crate::schema::Error::NoPrimaryKey)?;
+
+    let values: Vec<String> = match (pk.dtype, ids) {
+        (DataType::Int64, ValueVec::Long(values)) => values.iter().map(|v| v.to_string()).collect(),
+        (DataType::VarChar, ValueVec::String(values)) => {
+            values.iter().map(|v| quote_string(v)).collect()
+        }
+        _ => {
+            return Err(Error::InvalidParameter(
+                "pk type".to_owned(),
+                pk.dtype.as_str_name().to_owned(),
+            ));
+        }
+    };
+
+    Ok(format!("{} in [{}]", pk.name, values.join(",")))
+}
+
+/// Quotes a string literal of a boolean expression, escaping the quotes and backslashes in it.
+fn quote_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use super::quote_string;
+
+    #[test]
+    fn test_quote_string() {
+        assert_eq!(r#""book""#, quote_string("book"));
+        assert_eq!(r#""say \"hi\"""#, quote_string(r#"say "hi""#));
+        assert_eq!(r#""C:\\dir""#, quote_string(r"C:\dir"));
+        assert_eq!(r#""café""#, quote_string("café"));
+    }
+}
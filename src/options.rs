@@ -1,9 +1,17 @@
+use std::time::Duration;
+
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
+
+use crate::collection::CollectionProperties;
+use crate::config::REQUEST_ID_HEADER;
+use crate::error::{Error, Result};
 use crate::proto::common::ConsistencyLevel;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct CreateCollectionOptions {
     pub(crate) shard_num: i32,
     pub(crate) consistency_level: ConsistencyLevel,
+    pub(crate) properties: CollectionProperties,
 }
 
 impl Default for CreateCollectionOptions {
@@ -11,6 +19,7 @@ impl Default for CreateCollectionOptions {
         Self {
             shard_num: 0,
             consistency_level: ConsistencyLevel::Bounded,
+            properties: CollectionProperties::default(),
         }
     }
 }
@@ -37,16 +46,25 @@ impl CreateCollectionOptions {
         self.consistency_level = consistency_level;
         self
     }
+
+    pub fn properties(mut self, properties: CollectionProperties) -> Self {
+        self.properties = properties;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct LoadOptions {
     pub(crate) replica_number: i32,
+    pub(crate) timeout: Option<Duration>,
 }
 
 impl Default for LoadOptions {
     fn default() -> Self {
-        Self { replica_number: 1 }
+        Self {
+            replica_number: 1,
+            timeout: None,
+        }
     }
 }
 
@@ -63,6 +81,14 @@ impl LoadOptions {
         self.replica_number = replica_number;
         self
     }
+
+    /// How long [`Client::load_collection`](crate::client::Client::load_collection) waits
+    /// for the collection to be loaded, by default it's bounded only by the timeout
+    /// of the call options.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -92,3 +118,145 @@ impl GetLoadStateOptions {
         self
     }
 }
+
+#[derive(Debug, Clone, Default)]
+pub struct RenameCollectionOptions {
+    pub(crate) new_db_name: Option<String>,
+}
+
+impl RenameCollectionOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_new_db_name(new_db_name: &str) -> Self {
+        Self::default().new_db_name(new_db_name)
+    }
+
+    /// Moves the collection to another database, it stays in the current one by default.
+    pub fn new_db_name(mut self, new_db_name: &str) -> Self {
+        self.new_db_name = Some(new_db_name.to_owned());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListCollectionsOptions {
+    pub(crate) loaded_only: bool,
+    pub(crate) collection_names: Vec<String>,
+}
+
+impl ListCollectionsOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_loaded_only(loaded_only: bool) -> Self {
+        Self::default().loaded_only(loaded_only)
+    }
+
+    /// Only lists the collections loaded in memory, with their load percentages.
+    pub fn loaded_only(mut self, loaded_only: bool) -> Self {
+        self.loaded_only = loaded_only;
+        self
+    }
+
+    /// Restricts the loaded collections to the given ones, only used with `loaded_only`.
+    pub fn collection_names<I>(mut self, collection_names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.collection_names = collection_names.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// Options applied to every request sent by a client,
+/// see [`Client::with_call_options`](crate::client::Client::with_call_options).
+#[derive(Debug, Clone, Default)]
+pub struct CallOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) metadata: Vec<(String, String)>,
+    pub(crate) request_id: Option<String>,
+}
+
+impl CallOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self::default().timeout(timeout)
+    }
+
+    /// The deadline of each call including its retries, it replaces the timeout of the client.
+    ///
+    /// For `load_collection` and `create_index` it bounds the whole operation,
+    /// including waiting for the collection to be loaded or the index to be built.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a gRPC metadata header to every request,
+    /// keys and values must be ASCII, which is checked when the request is sent.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sends `request_id` as the `x-request-id` header to correlate the requests with server logs.
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    pub(crate) fn apply(&self, metadata: &mut MetadataMap) -> Result<()> {
+        let headers = self
+            .metadata
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .chain(self.request_id.as_deref().map(|id| (REQUEST_ID_HEADER, id)));
+
+        for (key, value) in headers {
+            let key: AsciiMetadataKey = key
+                .parse()
+                .map_err(|_| Error::InvalidParameter("metadata".to_owned(), key.to_owned()))?;
+            let value: AsciiMetadataValue = value
+                .parse()
+                .map_err(|_| Error::InvalidParameter(key.to_string(), value.to_owned()))?;
+            metadata.insert(key, value);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tonic::metadata::MetadataMap;
+
+    use super::CallOptions;
+
+    #[test]
+    fn test_call_options_metadata() {
+        let options = CallOptions::new()
+            .metadata("x-tenant", "books")
+            .request_id("req-1");
+        let mut metadata = MetadataMap::new();
+        options.apply(&mut metadata).unwrap();
+        assert_eq!("books", metadata.get("x-tenant").unwrap());
+        assert_eq!("req-1", metadata.get("x-request-id").unwrap());
+
+        let mut metadata = MetadataMap::new();
+        assert!(CallOptions::new()
+            .metadata("bad key", "v")
+            .apply(&mut metadata)
+            .is_err());
+        assert!(CallOptions::new()
+            .metadata("x-tenant", "line\nbreak")
+            .apply(&mut metadata)
+            .is_err());
+    }
+}
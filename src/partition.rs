@@ -1,13 +1,11 @@
-use std::collections::HashMap;
-
 use crate::error::*;
 use crate::{
     client::Client,
+    collection::CollectionStatistics,
     proto::{
         self,
         common::{MsgBase, MsgType},
     },
-    utils::status_to_result,
 };
 
 impl Client {
@@ -16,18 +14,17 @@ impl Client {
         collection_name: String,
         partition_name: String,
     ) -> Result<()> {
-        status_to_result(&Some(
-            self.client
-                .clone()
-                .create_partition(crate::proto::milvus::CreatePartitionRequest {
-                    base: Some(MsgBase::new(MsgType::CreatePartition)),
-                    db_name: "".to_string(), // reserved
-                    collection_name,
-                    partition_name,
-                })
-                .await?
-                .into_inner(),
-        ))
+        self.invoke(
+            crate::proto::milvus::CreatePartitionRequest {
+                base: Some(MsgBase::new(MsgType::CreatePartition)),
+                db_name: self.db_name.clone(),
+                collection_name,
+                partition_name,
+            },
+            |mut client, req| async move { client.create_partition(req).await },
+        )
+        .await?;
+        Ok(())
     }
 
     pub async fn drop_partition(
@@ -35,35 +32,33 @@ impl Client {
         collection_name: String,
         partition_name: String,
     ) -> Result<()> {
-        status_to_result(&Some(
-            self.client
-                .clone()
-                .drop_partition(crate::proto::milvus::DropPartitionRequest {
-                    base: Some(MsgBase::new(MsgType::DropPartition)),
-                    db_name: "".to_string(), // reserved
-                    collection_name,
-                    partition_name,
-                })
-                .await?
-                .into_inner(),
-        ))
+        self.invoke(
+            crate::proto::milvus::DropPartitionRequest {
+                base: Some(MsgBase::new(MsgType::DropPartition)),
+                db_name: self.db_name.clone(),
+                collection_name,
+                partition_name,
+            },
+            |mut client, req| async move { client.drop_partition(req).await },
+        )
+        .await?;
+        Ok(())
     }
 
     pub async fn list_partitions(&self, collection_name: String) -> Result<Vec<String>> {
         let res = self
-            .client
-            .clone()
-            .show_partitions(crate::proto::milvus::ShowPartitionsRequest {
-                base: Some(MsgBase::new(MsgType::ShowPartitions)),
-                db_name: "".to_string(), // reserved
-                collection_name,
-                collection_id: 0,        // reserved
-                partition_names: vec![], // reserved
-                r#type: 0,               // reserved
-            })
-            .await?
-            .into_inner();
-        status_to_result(&res.status)?;
+            .invoke(
+                crate::proto::milvus::ShowPartitionsRequest {
+                    base: Some(MsgBase::new(MsgType::ShowPartitions)),
+                    db_name: self.db_name.clone(),
+                    collection_name,
+                    collection_id: 0,        // reserved
+                    partition_names: vec![], // reserved
+                    r#type: 0,               // reserved
+                },
+                |mut client, req| async move { client.show_partitions(req).await },
+            )
+            .await?;
         Ok(res.partition_names)
     }
 
@@ -73,17 +68,16 @@ impl Client {
         partition_name: String,
     ) -> Result<bool> {
         let res = self
-            .client
-            .clone()
-            .has_partition(crate::proto::milvus::HasPartitionRequest {
-                base: Some(MsgBase::new(MsgType::HasPartition)),
-                db_name: "".to_string(), // reserved
-                collection_name,
-                partition_name,
-            })
-            .await?
-            .into_inner();
-        status_to_result(&res.status)?;
+            .invoke(
+                crate::proto::milvus::HasPartitionRequest {
+                    base: Some(MsgBase::new(MsgType::HasPartition)),
+                    db_name: self.db_name.clone(),
+                    collection_name,
+                    partition_name,
+                },
+                |mut client, req| async move { client.has_partition(req).await },
+            )
+            .await?;
         Ok(res.value)
     }
 
@@ -91,21 +85,20 @@ impl Client {
         &self,
         collection_name: String,
         partition_name: String,
-    ) -> Result<HashMap<String, String>> {
+    ) -> Result<CollectionStatistics> {
         let res = self
-            .client
-            .clone()
-            .get_partition_statistics(crate::proto::milvus::GetPartitionStatisticsRequest {
-                base: Some(MsgBase::new(MsgType::GetPartitionStatistics)),
-                db_name: "".to_string(), // reserved
-                collection_name,
-                partition_name,
-            })
-            .await?
-            .into_inner();
-        status_to_result(&res.status)?;
+            .invoke(
+                crate::proto::milvus::GetPartitionStatisticsRequest {
+                    base: Some(MsgBase::new(MsgType::GetPartitionStatistics)),
+                    db_name: self.db_name.clone(),
+                    collection_name,
+                    partition_name,
+                },
+                |mut client, req| async move { client.get_partition_statistics(req).await },
+            )
+            .await?;
 
-        Ok(res.stats.into_iter().map(|s| (s.key, s.value)).collect())
+        Ok(res.stats.into())
     }
 
     // pub async fn load_partitions<S: Into<String>, I: IntoIterator<Item = S>>(
@@ -2288,10 +2288,29 @@ pub mod milvus_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         pub async fn create_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateCollectionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2305,12 +2324,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreateCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "CreateCollection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn drop_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::DropCollectionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2324,12 +2354,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DropCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "DropCollection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn has_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::HasCollectionRequest>,
-        ) -> Result<tonic::Response<super::BoolResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::BoolResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2343,12 +2381,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/HasCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "HasCollection"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn load_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::LoadCollectionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2362,12 +2408,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/LoadCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "LoadCollection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn release_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::ReleaseCollectionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2381,12 +2438,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ReleaseCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ReleaseCollection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn describe_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::DescribeCollectionRequest>,
-        ) -> Result<tonic::Response<super::DescribeCollectionResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::DescribeCollectionResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2400,12 +2468,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DescribeCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "DescribeCollection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_collection_statistics(
             &mut self,
             request: impl tonic::IntoRequest<super::GetCollectionStatisticsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::GetCollectionStatisticsResponse>,
             tonic::Status,
         > {
@@ -2422,12 +2498,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetCollectionStatistics",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetCollectionStatistics",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn show_collections(
             &mut self,
             request: impl tonic::IntoRequest<super::ShowCollectionsRequest>,
-        ) -> Result<tonic::Response<super::ShowCollectionsResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ShowCollectionsResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2441,12 +2528,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ShowCollections",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ShowCollections",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn alter_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::AlterCollectionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2460,12 +2558,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/AlterCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "AlterCollection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn create_partition(
             &mut self,
             request: impl tonic::IntoRequest<super::CreatePartitionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2479,12 +2588,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreatePartition",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "CreatePartition",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn drop_partition(
             &mut self,
             request: impl tonic::IntoRequest<super::DropPartitionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2498,12 +2618,17 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DropPartition",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "DropPartition"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn has_partition(
             &mut self,
             request: impl tonic::IntoRequest<super::HasPartitionRequest>,
-        ) -> Result<tonic::Response<super::BoolResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::BoolResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2517,12 +2642,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/HasPartition",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "HasPartition"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn load_partitions(
             &mut self,
             request: impl tonic::IntoRequest<super::LoadPartitionsRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2536,12 +2669,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/LoadPartitions",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "LoadPartitions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn release_partitions(
             &mut self,
             request: impl tonic::IntoRequest<super::ReleasePartitionsRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2555,12 +2699,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ReleasePartitions",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ReleasePartitions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_partition_statistics(
             &mut self,
             request: impl tonic::IntoRequest<super::GetPartitionStatisticsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::GetPartitionStatisticsResponse>,
             tonic::Status,
         > {
@@ -2577,12 +2729,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetPartitionStatistics",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetPartitionStatistics",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn show_partitions(
             &mut self,
             request: impl tonic::IntoRequest<super::ShowPartitionsRequest>,
-        ) -> Result<tonic::Response<super::ShowPartitionsResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ShowPartitionsResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2596,12 +2759,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ShowPartitions",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ShowPartitions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_loading_progress(
             &mut self,
             request: impl tonic::IntoRequest<super::GetLoadingProgressRequest>,
-        ) -> Result<tonic::Response<super::GetLoadingProgressResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetLoadingProgressResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2615,12 +2789,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetLoadingProgress",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetLoadingProgress",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_load_state(
             &mut self,
             request: impl tonic::IntoRequest<super::GetLoadStateRequest>,
-        ) -> Result<tonic::Response<super::GetLoadStateResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetLoadStateResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2634,12 +2819,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetLoadState",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "GetLoadState"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn create_alias(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateAliasRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2653,12 +2846,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreateAlias",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "CreateAlias"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn drop_alias(
             &mut self,
             request: impl tonic::IntoRequest<super::DropAliasRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2672,12 +2873,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DropAlias",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "DropAlias"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn alter_alias(
             &mut self,
             request: impl tonic::IntoRequest<super::AlterAliasRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2691,12 +2900,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/AlterAlias",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "AlterAlias"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn describe_alias(
             &mut self,
             request: impl tonic::IntoRequest<super::DescribeAliasRequest>,
-        ) -> Result<tonic::Response<super::DescribeAliasResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::DescribeAliasResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2710,12 +2927,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DescribeAlias",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "DescribeAlias"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn list_aliases(
             &mut self,
             request: impl tonic::IntoRequest<super::ListAliasesRequest>,
-        ) -> Result<tonic::Response<super::ListAliasesResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ListAliasesResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2729,12 +2954,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ListAliases",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "ListAliases"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn create_index(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateIndexRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2748,12 +2981,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreateIndex",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "CreateIndex"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn alter_index(
             &mut self,
             request: impl tonic::IntoRequest<super::AlterIndexRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2767,12 +3008,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/AlterIndex",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "AlterIndex"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn describe_index(
             &mut self,
             request: impl tonic::IntoRequest<super::DescribeIndexRequest>,
-        ) -> Result<tonic::Response<super::DescribeIndexResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::DescribeIndexResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2786,12 +3035,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DescribeIndex",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "DescribeIndex"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_index_statistics(
             &mut self,
             request: impl tonic::IntoRequest<super::GetIndexStatisticsRequest>,
-        ) -> Result<tonic::Response<super::GetIndexStatisticsResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetIndexStatisticsResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2805,13 +3062,24 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetIndexStatistics",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetIndexStatistics",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Deprecated: use DescribeIndex instead
         pub async fn get_index_state(
             &mut self,
             request: impl tonic::IntoRequest<super::GetIndexStateRequest>,
-        ) -> Result<tonic::Response<super::GetIndexStateResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetIndexStateResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2825,13 +3093,18 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetIndexState",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "GetIndexState"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Deprecated: use DescribeIndex instead
         pub async fn get_index_build_progress(
             &mut self,
             request: impl tonic::IntoRequest<super::GetIndexBuildProgressRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::GetIndexBuildProgressResponse>,
             tonic::Status,
         > {
@@ -2848,12 +3121,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetIndexBuildProgress",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetIndexBuildProgress",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn drop_index(
             &mut self,
             request: impl tonic::IntoRequest<super::DropIndexRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2867,12 +3151,17 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DropIndex",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "DropIndex"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn insert(
             &mut self,
             request: impl tonic::IntoRequest<super::InsertRequest>,
-        ) -> Result<tonic::Response<super::MutationResult>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::MutationResult>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2886,12 +3175,15 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Insert",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Insert"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn delete(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteRequest>,
-        ) -> Result<tonic::Response<super::MutationResult>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::MutationResult>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2905,12 +3197,15 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Delete",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Delete"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn upsert(
             &mut self,
             request: impl tonic::IntoRequest<super::UpsertRequest>,
-        ) -> Result<tonic::Response<super::MutationResult>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::MutationResult>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2924,12 +3219,15 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Upsert",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Upsert"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn search(
             &mut self,
             request: impl tonic::IntoRequest<super::SearchRequest>,
-        ) -> Result<tonic::Response<super::SearchResults>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::SearchResults>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2943,12 +3241,15 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Search",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Search"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn search_v2(
             &mut self,
             request: impl tonic::IntoRequest<super::SearchRequestV2>,
-        ) -> Result<tonic::Response<super::SearchResults>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::SearchResults>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2962,12 +3263,17 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/SearchV2",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "SearchV2"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn flush(
             &mut self,
             request: impl tonic::IntoRequest<super::FlushRequest>,
-        ) -> Result<tonic::Response<super::FlushResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::FlushResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -2981,12 +3287,15 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Flush",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Flush"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn query(
             &mut self,
             request: impl tonic::IntoRequest<super::QueryRequest>,
-        ) -> Result<tonic::Response<super::QueryResults>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::QueryResults>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -3000,12 +3309,18 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Query",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Query"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn calc_distance(
             &mut self,
             request: impl tonic::IntoRequest<super::CalcDistanceRequest>,
-        ) -> Result<tonic::Response<super::CalcDistanceResults>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::CalcDistanceResults>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3019,12 +3334,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CalcDistance",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "CalcDistance"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn flush_all(
             &mut self,
             request: impl tonic::IntoRequest<super::FlushAllRequest>,
-        ) -> Result<tonic::Response<super::FlushAllResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::FlushAllResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3038,12 +3361,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/FlushAll",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "FlushAll"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_flush_state(
             &mut self,
             request: impl tonic::IntoRequest<super::GetFlushStateRequest>,
-        ) -> Result<tonic::Response<super::GetFlushStateResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetFlushStateResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3057,12 +3388,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetFlushState",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "GetFlushState"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_flush_all_state(
             &mut self,
             request: impl tonic::IntoRequest<super::GetFlushAllStateRequest>,
-        ) -> Result<tonic::Response<super::GetFlushAllStateResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetFlushAllStateResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3076,12 +3415,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetFlushAllState",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetFlushAllState",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_persistent_segment_info(
             &mut self,
             request: impl tonic::IntoRequest<super::GetPersistentSegmentInfoRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::GetPersistentSegmentInfoResponse>,
             tonic::Status,
         > {
@@ -3098,12 +3445,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetPersistentSegmentInfo",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetPersistentSegmentInfo",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_query_segment_info(
             &mut self,
             request: impl tonic::IntoRequest<super::GetQuerySegmentInfoRequest>,
-        ) -> Result<tonic::Response<super::GetQuerySegmentInfoResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetQuerySegmentInfoResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3117,12 +3475,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetQuerySegmentInfo",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetQuerySegmentInfo",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_replicas(
             &mut self,
             request: impl tonic::IntoRequest<super::GetReplicasRequest>,
-        ) -> Result<tonic::Response<super::GetReplicasResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetReplicasResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3136,12 +3505,17 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetReplicas",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "GetReplicas"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn dummy(
             &mut self,
             request: impl tonic::IntoRequest<super::DummyRequest>,
-        ) -> Result<tonic::Response<super::DummyResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::DummyResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -3155,13 +3529,19 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Dummy",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Dummy"));
+            self.inner.unary(req, path, codec).await
         }
         /// TODO: remove
         pub async fn register_link(
             &mut self,
             request: impl tonic::IntoRequest<super::RegisterLinkRequest>,
-        ) -> Result<tonic::Response<super::RegisterLinkResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::RegisterLinkResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3175,13 +3555,21 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/RegisterLink",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "RegisterLink"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// https://wiki.lfaidata.foundation/display/MIL/MEP+8+--+Add+metrics+for+proxy
         pub async fn get_metrics(
             &mut self,
             request: impl tonic::IntoRequest<super::GetMetricsRequest>,
-        ) -> Result<tonic::Response<super::GetMetricsResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetMetricsResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3195,12 +3583,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetMetrics",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "GetMetrics"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_component_states(
             &mut self,
             request: impl tonic::IntoRequest<super::GetComponentStatesRequest>,
-        ) -> Result<tonic::Response<super::ComponentStates>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ComponentStates>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3214,12 +3610,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetComponentStates",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetComponentStates",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn load_balance(
             &mut self,
             request: impl tonic::IntoRequest<super::LoadBalanceRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3233,12 +3640,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/LoadBalance",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "LoadBalance"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_compaction_state(
             &mut self,
             request: impl tonic::IntoRequest<super::GetCompactionStateRequest>,
-        ) -> Result<tonic::Response<super::GetCompactionStateResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetCompactionStateResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3252,12 +3667,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetCompactionState",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetCompactionState",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn manual_compaction(
             &mut self,
             request: impl tonic::IntoRequest<super::ManualCompactionRequest>,
-        ) -> Result<tonic::Response<super::ManualCompactionResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ManualCompactionResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3271,12 +3697,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ManualCompaction",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ManualCompaction",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_compaction_state_with_plans(
             &mut self,
             request: impl tonic::IntoRequest<super::GetCompactionPlansRequest>,
-        ) -> Result<tonic::Response<super::GetCompactionPlansResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetCompactionPlansResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3290,13 +3727,21 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetCompactionStateWithPlans",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetCompactionStateWithPlans",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// https://wiki.lfaidata.foundation/display/MIL/MEP+24+--+Support+bulk+load
         pub async fn import(
             &mut self,
             request: impl tonic::IntoRequest<super::ImportRequest>,
-        ) -> Result<tonic::Response<super::ImportResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ImportResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -3310,12 +3755,18 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Import",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Import"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_import_state(
             &mut self,
             request: impl tonic::IntoRequest<super::GetImportStateRequest>,
-        ) -> Result<tonic::Response<super::GetImportStateResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetImportStateResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3329,12 +3780,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetImportState",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "GetImportState",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn list_import_tasks(
             &mut self,
             request: impl tonic::IntoRequest<super::ListImportTasksRequest>,
-        ) -> Result<tonic::Response<super::ListImportTasksResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ListImportTasksResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3348,13 +3810,24 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ListImportTasks",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ListImportTasks",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// https://wiki.lfaidata.foundation/display/MIL/MEP+27+--+Support+Basic+Authentication
         pub async fn create_credential(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateCredentialRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3368,12 +3841,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreateCredential",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "CreateCredential",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn update_credential(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateCredentialRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3387,12 +3871,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/UpdateCredential",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "UpdateCredential",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn delete_credential(
             &mut self,
             request: impl tonic::IntoRequest<super::DeleteCredentialRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3406,12 +3901,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DeleteCredential",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "DeleteCredential",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn list_cred_users(
             &mut self,
             request: impl tonic::IntoRequest<super::ListCredUsersRequest>,
-        ) -> Result<tonic::Response<super::ListCredUsersResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ListCredUsersResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3425,13 +3931,21 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ListCredUsers",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "ListCredUsers"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// https://wiki.lfaidata.foundation/display/MIL/MEP+29+--+Support+Role-Based+Access+Control
         pub async fn create_role(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateRoleRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3445,12 +3959,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreateRole",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "CreateRole"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn drop_role(
             &mut self,
             request: impl tonic::IntoRequest<super::DropRoleRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3464,12 +3986,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DropRole",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "DropRole"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn operate_user_role(
             &mut self,
             request: impl tonic::IntoRequest<super::OperateUserRoleRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3483,12 +4013,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/OperateUserRole",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "OperateUserRole",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn select_role(
             &mut self,
             request: impl tonic::IntoRequest<super::SelectRoleRequest>,
-        ) -> Result<tonic::Response<super::SelectRoleResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::SelectRoleResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3502,12 +4043,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/SelectRole",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "SelectRole"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn select_user(
             &mut self,
             request: impl tonic::IntoRequest<super::SelectUserRequest>,
-        ) -> Result<tonic::Response<super::SelectUserResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::SelectUserResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3521,12 +4070,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/SelectUser",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "SelectUser"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn operate_privilege(
             &mut self,
             request: impl tonic::IntoRequest<super::OperatePrivilegeRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3540,12 +4097,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/OperatePrivilege",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "OperatePrivilege",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn select_grant(
             &mut self,
             request: impl tonic::IntoRequest<super::SelectGrantRequest>,
-        ) -> Result<tonic::Response<super::SelectGrantResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::SelectGrantResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3559,12 +4127,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/SelectGrant",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "SelectGrant"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn get_version(
             &mut self,
             request: impl tonic::IntoRequest<super::GetVersionRequest>,
-        ) -> Result<tonic::Response<super::GetVersionResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::GetVersionResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3578,12 +4154,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/GetVersion",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "GetVersion"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn check_health(
             &mut self,
             request: impl tonic::IntoRequest<super::CheckHealthRequest>,
-        ) -> Result<tonic::Response<super::CheckHealthResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::CheckHealthResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3597,12 +4181,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CheckHealth",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "CheckHealth"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn create_resource_group(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateResourceGroupRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3616,12 +4208,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreateResourceGroup",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "CreateResourceGroup",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn drop_resource_group(
             &mut self,
             request: impl tonic::IntoRequest<super::DropResourceGroupRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3635,12 +4238,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DropResourceGroup",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "DropResourceGroup",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn transfer_node(
             &mut self,
             request: impl tonic::IntoRequest<super::TransferNodeRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3654,12 +4268,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/TransferNode",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "TransferNode"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn transfer_replica(
             &mut self,
             request: impl tonic::IntoRequest<super::TransferReplicaRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3673,12 +4295,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/TransferReplica",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "TransferReplica",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn list_resource_groups(
             &mut self,
             request: impl tonic::IntoRequest<super::ListResourceGroupsRequest>,
-        ) -> Result<tonic::Response<super::ListResourceGroupsResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ListResourceGroupsResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3692,12 +4325,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ListResourceGroups",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ListResourceGroups",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn describe_resource_group(
             &mut self,
             request: impl tonic::IntoRequest<super::DescribeResourceGroupRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::DescribeResourceGroupResponse>,
             tonic::Status,
         > {
@@ -3714,12 +4355,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DescribeResourceGroup",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "DescribeResourceGroup",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn rename_collection(
             &mut self,
             request: impl tonic::IntoRequest<super::RenameCollectionRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3733,14 +4385,22 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/RenameCollection",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "RenameCollection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn list_indexed_segment(
             &mut self,
             request: impl tonic::IntoRequest<
                 super::super::feder::ListIndexedSegmentRequest,
             >,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::super::feder::ListIndexedSegmentResponse>,
             tonic::Status,
         > {
@@ -3757,14 +4417,22 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ListIndexedSegment",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ListIndexedSegment",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn describe_segment_index_data(
             &mut self,
             request: impl tonic::IntoRequest<
                 super::super::feder::DescribeSegmentIndexDataRequest,
             >,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::super::feder::DescribeSegmentIndexDataResponse>,
             tonic::Status,
         > {
@@ -3781,12 +4449,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DescribeSegmentIndexData",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "DescribeSegmentIndexData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn connect(
             &mut self,
             request: impl tonic::IntoRequest<super::ConnectRequest>,
-        ) -> Result<tonic::Response<super::ConnectResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ConnectResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3800,12 +4479,18 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/Connect",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("milvus.proto.milvus.MilvusService", "Connect"));
+            self.inner.unary(req, path, codec).await
         }
         pub async fn alloc_timestamp(
             &mut self,
             request: impl tonic::IntoRequest<super::AllocTimestampRequest>,
-        ) -> Result<tonic::Response<super::AllocTimestampResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::AllocTimestampResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3819,12 +4504,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/AllocTimestamp",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "AllocTimestamp",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn create_database(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateDatabaseRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3838,12 +4534,23 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/CreateDatabase",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "CreateDatabase",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn drop_database(
             &mut self,
             request: impl tonic::IntoRequest<super::DropDatabaseRequest>,
-        ) -> Result<tonic::Response<super::super::common::Status>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::Status>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3857,12 +4564,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/DropDatabase",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "DropDatabase"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn list_databases(
             &mut self,
             request: impl tonic::IntoRequest<super::ListDatabasesRequest>,
-        ) -> Result<tonic::Response<super::ListDatabasesResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ListDatabasesResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3876,12 +4591,20 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ListDatabases",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.MilvusService", "ListDatabases"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn replicate_message(
             &mut self,
             request: impl tonic::IntoRequest<super::ReplicateMessageRequest>,
-        ) -> Result<tonic::Response<super::ReplicateMessageResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::ReplicateMessageResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3895,7 +4618,15 @@ pub mod milvus_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.MilvusService/ReplicateMessage",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "milvus.proto.milvus.MilvusService",
+                        "ReplicateMessage",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
     }
 }
@@ -3957,10 +4688,29 @@ pub mod proxy_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         pub async fn register_link(
             &mut self,
             request: impl tonic::IntoRequest<super::RegisterLinkRequest>,
-        ) -> Result<tonic::Response<super::RegisterLinkResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::RegisterLinkResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -3974,7 +4724,12 @@ pub mod proxy_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/milvus.proto.milvus.ProxyService/RegisterLink",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("milvus.proto.milvus.ProxyService", "RegisterLink"),
+                );
+            self.inner.unary(req, path, codec).await
         }
     }
 }
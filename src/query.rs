@@ -14,7 +14,6 @@ use crate::proto::common::{
 };
 use crate::proto::milvus::SearchRequest;
 use crate::proto::schema::DataType;
-use crate::utils::status_to_result;
 use crate::value::Value;
 use crate::{error::*, proto};
 
@@ -22,10 +21,13 @@ const STRONG_TIMESTAMP: u64 = 0;
 const BOUNDED_TIMESTAMP: u64 = 2;
 const EVENTUALLY_TIMESTAMP: u64 = 1;
 
+const COUNT_OUTPUT_FIELD: &str = "count(*)";
+
 #[derive(Debug, Clone)]
 pub struct QueryOptions {
     output_fields: Vec<String>,
     partition_names: Vec<String>,
+    consistency_level: Option<ConsistencyLevel>,
 }
 
 impl Default for QueryOptions {
@@ -33,6 +35,7 @@ impl Default for QueryOptions {
         Self {
             output_fields: Vec::new(),
             partition_names: Vec::new(),
+            consistency_level: None,
         }
     }
 }
@@ -50,6 +53,10 @@ impl QueryOptions {
         Self::default().partition_names(partition_names)
     }
 
+    pub fn with_consistency_level(consistency_level: ConsistencyLevel) -> Self {
+        Self::default().consistency_level(consistency_level)
+    }
+
     pub fn output_fields(mut self, output_fields: Vec<String>) -> Self {
         self.output_fields = output_fields;
         self
@@ -59,6 +66,12 @@ impl QueryOptions {
         self.partition_names = partition_names;
         self
     }
+
+    /// Overrides the consistency level of the collection for this query.
+    pub fn consistency_level(mut self, consistency_level: ConsistencyLevel) -> Self {
+        self.consistency_level = Some(consistency_level);
+        self
+    }
 }
 
 pub struct SearchOptions {
@@ -173,7 +186,7 @@ impl Client {
             ConsistencyLevel::Eventually => EVENTUALLY_TIMESTAMP,
             ConsistencyLevel::Session => self
                 .collection_cache
-                .get_timestamp(collection_name)
+                .get_timestamp(&self.db_name, collection_name)
                 .unwrap_or(EVENTUALLY_TIMESTAMP),
 
             // This level not works for now
@@ -192,43 +205,75 @@ impl Client {
         Exp: AsRef<str>,
     {
         let collection_name = collection_name.as_ref();
-        let collection = self.collection_cache.get(collection_name).await?;
-
-        let consistency_level = collection.consistency_level;
-        let mut output_fields = options.output_fields.clone();
-        if output_fields.is_empty() {
-            output_fields = collection.fields.iter().map(|f| f.name.clone()).collect();
-        }
+        let expr = expr.as_ref();
 
         let res = self
-            .client
-            .clone()
-            .query(proto::milvus::QueryRequest {
-                base: Some(MsgBase::new(MsgType::Retrieve)),
-                db_name: "".to_owned(),
-                collection_name: collection_name.to_owned(),
-                expr: expr.as_ref().to_owned(),
-                output_fields: output_fields,
-                partition_names: options.partition_names.clone(),
-                travel_timestamp: 0,
-                guarantee_timestamp: self
-                    .get_gts_from_consistency(collection_name, consistency_level)
-                    .await,
-                query_params: Vec::new(),
-                not_return_all_meta: false,
-                consistency_level: ConsistencyLevel::default() as _,
-                use_default_consistency: false,
-            })
-            .await?
-            .into_inner();
+            .with_collection(collection_name, |collection| async move {
+                let mut output_fields = options.output_fields.clone();
+                if output_fields.is_empty() {
+                    output_fields = collection.fields.iter().map(|f| f.name.clone()).collect();
+                }
 
-        status_to_result(&res.status)?;
+                self.invoke(
+                    proto::milvus::QueryRequest {
+                        base: Some(MsgBase::new(MsgType::Retrieve)),
+                        db_name: self.db_name.clone(),
+                        collection_name: collection_name.to_owned(),
+                        expr: expr.to_owned(),
+                        output_fields: output_fields,
+                        partition_names: options.partition_names.clone(),
+                        travel_timestamp: 0,
+                        guarantee_timestamp: self
+                            .get_gts_from_consistency(
+                                collection_name,
+                                options
+                                    .consistency_level
+                                    .unwrap_or(collection.consistency_level),
+                            )
+                            .await,
+                        query_params: Vec::new(),
+                        not_return_all_meta: false,
+                        consistency_level: ConsistencyLevel::default() as _,
+                        use_default_consistency: false,
+                    },
+                    |mut client, req| async move { client.query(req).await },
+                )
+                .await
+            })
+            .await?;
 
-        Ok(res
-            .fields_data
+        res.fields_data
             .into_iter()
-            .map(|f| FieldColumn::from(f))
-            .collect())
+            .map(FieldColumn::try_from)
+            .collect()
+    }
+
+    /// Counts the rows matching `expr`, or all of them if it's empty,
+    /// with a `count(*)` query honoring the partitions and consistency level of `options`.
+    ///
+    /// Unlike the `row_count` of [`Client::get_collection_stats`], deleted rows are excluded
+    /// and rows not flushed yet are included. The collection must be loaded.
+    pub async fn count<S, Exp>(
+        &self,
+        collection_name: S,
+        expr: Exp,
+        options: &QueryOptions,
+    ) -> Result<u64>
+    where
+        S: AsRef<str>,
+        Exp: AsRef<str>,
+    {
+        let options = options
+            .clone()
+            .output_fields(vec![COUNT_OUTPUT_FIELD.to_owned()]);
+        let columns = self.query(collection_name, expr, &options).await?;
+
+        match columns.first().and_then(|column| column.get(0)) {
+            Some(Value::Long(count)) => Ok(count as u64),
+            _ => Err(SuperError::Unexpected(
+                "no count in query result".to_owned(),
+            )),
+        }
     }
 
     pub async fn search<S>(
@@ -266,88 +311,121 @@ impl Client {
         ];
 
         let collection_name = collection_name.into();
-        let collection = self.collection_cache.get(&collection_name).await?;
+        let nq = data.len();
+        let placeholder_group = get_place_holder_group(data)?;
+        let (collection_name, search_params, placeholder_group) =
+            (&collection_name, &search_params, &placeholder_group);
 
         let res = self
-            .client
-            .clone()
-            .search(SearchRequest {
-                base: Some(MsgBase::new(MsgType::Search)),
-                db_name: "".to_string(),
-                collection_name: collection_name.clone(),
-                partition_names: option.partitions.clone(),
-                dsl: option.expr.clone(),
-                nq: data.len() as _,
-                placeholder_group: get_place_holder_group(data)?,
-                dsl_type: DslType::BoolExprV1 as _,
-                output_fields: option
-                    .output_fields
-                    .clone()
-                    .into_iter()
-                    .map(|f| f.into())
-                    .collect(),
-                search_params,
-                travel_timestamp: 0,
-                guarantee_timestamp: self
-                    .get_gts_from_consistency(&collection_name, collection.consistency_level)
-                    .await,
-                not_return_all_meta: false,
-                consistency_level: ConsistencyLevel::default() as _,
-                use_default_consistency: false,
-                search_by_primary_keys: false,
+            .with_collection(collection_name, |collection| async move {
+                self.invoke(
+                    SearchRequest {
+                        base: Some(MsgBase::new(MsgType::Search)),
+                        db_name: self.db_name.clone(),
+                        collection_name: collection_name.clone(),
+                        partition_names: option.partitions.clone(),
+                        dsl: option.expr.clone(),
+                        nq: nq as _,
+                        placeholder_group: placeholder_group.clone(),
+                        dsl_type: DslType::BoolExprV1 as _,
+                        output_fields: option
+                            .output_fields
+                            .clone()
+                            .into_iter()
+                            .map(|f| f.into())
+                            .collect(),
+                        search_params: search_params.clone(),
+                        travel_timestamp: 0,
+                        guarantee_timestamp: self
+                            .get_gts_from_consistency(collection_name, collection.consistency_level)
+                            .await,
+                        not_return_all_meta: false,
+                        consistency_level: ConsistencyLevel::default() as _,
+                        use_default_consistency: false,
+                        search_by_primary_keys: false,
+                    },
+                    |mut client, req| async move { client.search(req).await },
+                )
+                .await
             })
-            .await?
-            .into_inner();
-        status_to_result(&res.status)?;
+            .await?;
         let raw_data = res
             .results
             .ok_or(SuperError::Unexpected("no result for search".to_owned()))?;
-        let mut result = Vec::new();
-        let mut offset = 0;
-        let fields_data = raw_data
-            .fields_data
-            .into_iter()
-            .map(Into::into)
+        split_search_results(raw_data)
+    }
+}
+
+/// Splits the results of all the searched vectors, concatenated by the server,
+/// into one result per vector.
+fn split_search_results(
+    raw_data: proto::schema::SearchResultData,
+) -> Result<Vec<SearchResult<'static>>> {
+    let mut result = Vec::new();
+    let mut offset = 0usize;
+    let fields_data = raw_data
+        .fields_data
+        .into_iter()
+        .map(FieldColumn::try_from)
+        .collect::<Result<Vec<FieldColumn>>>()?;
+    let raw_id = raw_data
+        .ids
+        .and_then(|ids| ids.id_field)
+        .ok_or(SuperError::Unexpected("no ids in search result".to_owned()))?;
+
+    // The topks come from the server, they may not add up to the returned data
+    let out_of_range =
+        || SuperError::Unexpected("topks exceed the data of the search result".to_owned());
+    for k in raw_data.topks {
+        let end = usize::try_from(k)
+            .ok()
+            .and_then(|k| offset.checked_add(k))
+            .ok_or_else(out_of_range)?;
+        let score = raw_data
+            .scores
+            .get(offset..end)
+            .ok_or_else(out_of_range)?
+            .to_vec();
+        let mut result_data = fields_data
+            .iter()
+            .map(FieldColumn::copy_with_metadata)
             .collect::<Vec<FieldColumn>>();
-        let raw_id = raw_data.ids.unwrap().id_field.unwrap();
-
-        for k in raw_data.topks {
-            let k = k as usize;
-            let mut score = Vec::new();
-            score.extend_from_slice(&raw_data.scores[offset..offset + k]);
-            let mut result_data = fields_data
-                .iter()
-                .map(FieldColumn::copy_with_metadata)
-                .collect::<Vec<FieldColumn>>();
-            for j in 0..fields_data.len() {
-                for i in offset..offset + k {
-                    result_data[j].push(fields_data[j].get(i).ok_or(SuperError::Unexpected(
-                        "out of range while indexing field data".to_owned(),
-                    ))?);
-                }
+        for j in 0..fields_data.len() {
+            for i in offset..end {
+                result_data[j].push(fields_data[j].get(i).ok_or(SuperError::Unexpected(
+                    "out of range while indexing field data".to_owned(),
+                ))?)?;
             }
-
-            let id = match raw_id {
-                proto::schema::i_ds::IdField::IntId(ref d) => {
-                    Vec::<Value>::from_iter(d.data[offset..offset + k].iter().map(|&x| x.into()))
-                }
-                proto::schema::i_ds::IdField::StrId(ref d) => Vec::<Value>::from_iter(
-                    d.data[offset..offset + k].iter().map(|x| x.clone().into()),
-                ),
-            };
-
-            result.push(SearchResult {
-                size: k as i64,
-                score,
-                field: result_data,
-                id,
-            });
-
-            offset += k;
         }
 
-        Ok(result)
+        let id = match raw_id {
+            proto::schema::i_ds::IdField::IntId(ref d) => Vec::<Value>::from_iter(
+                d.data
+                    .get(offset..end)
+                    .ok_or_else(out_of_range)?
+                    .iter()
+                    .map(|&x| x.into()),
+            ),
+            proto::schema::i_ds::IdField::StrId(ref d) => Vec::<Value>::from_iter(
+                d.data
+                    .get(offset..end)
+                    .ok_or_else(out_of_range)?
+                    .iter()
+                    .map(|x| x.clone().into()),
+            ),
+        };
+
+        result.push(SearchResult {
+            size: k,
+            score,
+            field: result_data,
+            id,
+        });
+
+        offset = end;
     }
+
+    Ok(result)
 }
 
 fn get_place_holder_group(vectors: Vec<Value>) -> Result<Vec<u8>> {
@@ -355,7 +433,7 @@ fn get_place_holder_group(vectors: Vec<Value>) -> Result<Vec<u8>> {
         placeholders: vec![get_place_holder_value(vectors)?],
     };
     let mut buf = BytesMut::new();
-    group.encode(&mut buf).unwrap();
+    group.encode(&mut buf)?;
     return Ok(buf.to_vec());
 }
 
@@ -401,3 +479,41 @@ fn get_place_holder_value(vectors: Vec<Value>) -> Result<PlaceholderValue> {
     }
     return Ok(place_holder);
 }
+
+#[cfg(test)]
+mod test {
+    use super::split_search_results;
+    use crate::error::Error;
+    use crate::proto::schema::{i_ds::IdField, IDs, LongArray, SearchResultData};
+    use crate::value::Value;
+
+    fn search_result(topks: Vec<i64>, ids: Vec<i64>) -> SearchResultData {
+        SearchResultData {
+            scores: ids.iter().map(|&id| id as f32).collect(),
+            ids: Some(IDs {
+                id_field: Some(IdField::IntId(LongArray { data: ids })),
+            }),
+            topks,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_split_search_results() {
+        let results = split_search_results(search_result(vec![2, 1], vec![1, 2, 3])).unwrap();
+        assert_eq!(2, results.len());
+        assert_eq!(2, results[0].size);
+        assert_eq!(vec![1.0, 2.0], results[0].score);
+        assert!(matches!(results[1].id[..], [Value::Long(3)]));
+
+        // The topks add up to more than the returned data
+        assert!(matches!(
+            split_search_results(search_result(vec![2, 2], vec![1, 2, 3])),
+            Err(Error::Unexpected(_))
+        ));
+        assert!(matches!(
+            split_search_results(search_result(vec![-1], vec![1])),
+            Err(Error::Unexpected(_))
+        ));
+    }
+}
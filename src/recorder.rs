@@ -0,0 +1,218 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// What happened during a single call of the client, retries included.
+#[derive(Debug)]
+pub struct RpcEvent<'a> {
+    /// The name of the RPC, e.g. `Insert` or `Search`.
+    pub method: &'static str,
+    pub database: &'a str,
+    pub collection: Option<&'a str>,
+    /// The client-observed latency, from sending the first attempt to receiving the last response.
+    pub latency: Duration,
+    /// The encoded size of the request message.
+    pub bytes_sent: u64,
+    /// The number of rows returned by a query or search.
+    pub rows_returned: Option<u64>,
+    pub error: Option<&'a Error>,
+}
+
+impl RpcEvent<'_> {
+    /// The error code of the failed call, see [`Error::code_name`].
+    pub fn error_code(&self) -> Option<String> {
+        self.error.map(Error::code_name)
+    }
+}
+
+/// Observes every call of a `Client`, set with
+/// [`ClientBuilder::metrics_recorder`](crate::client::ClientBuilder::metrics_recorder).
+///
+/// `record` is called on the task that sent the request, so it should return quickly.
+pub trait MetricsRecorder: Send + Sync {
+    fn record(&self, event: &RpcEvent<'_>);
+}
+
+/// The recorder shared by a `Client` and all of its clones.
+#[derive(Clone, Default)]
+pub(crate) struct SharedRecorder(pub(crate) Option<Arc<dyn MetricsRecorder>>);
+
+impl std::fmt::Debug for SharedRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedRecorder")
+            .field("enabled", &self.0.is_some())
+            .finish()
+    }
+}
+
+#[cfg(feature = "prometheus")]
+pub use self::prometheus_recorder::PrometheusRecorder;
+
+#[cfg(feature = "prometheus")]
+mod prometheus_recorder {
+    use prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+
+    use super::{MetricsRecorder, RpcEvent};
+    use crate::error::Result;
+
+    /// Records the calls of a client as Prometheus metrics, labeled by method:
+    ///
+    /// - `milvus_client_requests_total`
+    /// - `milvus_client_errors_total`, also labeled by error code
+    /// - `milvus_client_request_duration_seconds`
+    /// - `milvus_client_sent_bytes_total`
+    /// - `milvus_client_returned_rows_total`
+    #[derive(Clone)]
+    pub struct PrometheusRecorder {
+        requests: IntCounterVec,
+        errors: IntCounterVec,
+        latency: HistogramVec,
+        bytes_sent: IntCounterVec,
+        rows_returned: IntCounterVec,
+    }
+
+    impl PrometheusRecorder {
+        /// Creates the metrics and registers them to `registry`.
+        pub fn new(registry: &Registry) -> Result<Self> {
+            let recorder = Self {
+                requests: IntCounterVec::new(
+                    Opts::new("milvus_client_requests_total", "Requests sent to Milvus"),
+                    &["method"],
+                )
+                .map_err(anyhow::Error::from)?,
+                errors: IntCounterVec::new(
+                    Opts::new(
+                        "milvus_client_errors_total",
+                        "Requests to Milvus that failed",
+                    ),
+                    &["method", "code"],
+                )
+                .map_err(anyhow::Error::from)?,
+                latency: HistogramVec::new(
+                    HistogramOpts::new(
+                        "milvus_client_request_duration_seconds",
+                        "Latency of requests to Milvus, retries included",
+                    ),
+                    &["method"],
+                )
+                .map_err(anyhow::Error::from)?,
+                bytes_sent: IntCounterVec::new(
+                    Opts::new("milvus_client_sent_bytes_total", "Bytes sent to Milvus"),
+                    &["method"],
+                )
+                .map_err(anyhow::Error::from)?,
+                rows_returned: IntCounterVec::new(
+                    Opts::new(
+                        "milvus_client_returned_rows_total",
+                        "Rows returned by queries and searches",
+                    ),
+                    &["method"],
+                )
+                .map_err(anyhow::Error::from)?,
+            };
+
+            registry
+                .register(Box::new(recorder.requests.clone()))
+                .and_then(|_| registry.register(Box::new(recorder.errors.clone())))
+                .and_then(|_| registry.register(Box::new(recorder.latency.clone())))
+                .and_then(|_| registry.register(Box::new(recorder.bytes_sent.clone())))
+                .and_then(|_| registry.register(Box::new(recorder.rows_returned.clone())))
+                .map_err(anyhow::Error::from)?;
+
+            Ok(recorder)
+        }
+    }
+
+    impl MetricsRecorder for PrometheusRecorder {
+        fn record(&self, event: &RpcEvent<'_>) {
+            let method = [event.method];
+            self.requests.with_label_values(&method).inc();
+            self.latency
+                .with_label_values(&method)
+                .observe(event.latency.as_secs_f64());
+            self.bytes_sent
+                .with_label_values(&method)
+                .inc_by(event.bytes_sent);
+            if let Some(rows) = event.rows_returned {
+                self.rows_returned.with_label_values(&method).inc_by(rows);
+            }
+            if let Some(code) = event.error_code() {
+                self.errors.with_label_values(&[event.method, &code]).inc();
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use std::time::Duration;
+
+        use prometheus::Registry;
+
+        use super::PrometheusRecorder;
+        use crate::error::Error;
+        use crate::proto::common::ErrorCode;
+        use crate::recorder::{MetricsRecorder, RpcEvent};
+
+        #[test]
+        fn test_prometheus_recorder() {
+            let registry = Registry::new();
+            let recorder = PrometheusRecorder::new(&registry).unwrap();
+            let err = Error::Server(ErrorCode::RateLimit, "rate limited".to_owned());
+
+            let mut event = RpcEvent {
+                method: "Search",
+                database: "",
+                collection: Some("book"),
+                latency: Duration::from_millis(20),
+                bytes_sent: 128,
+                rows_returned: Some(10),
+                error: None,
+            };
+            recorder.record(&event);
+            event.rows_returned = None;
+            event.error = Some(&err);
+            recorder.record(&event);
+
+            assert_eq!(2, recorder.requests.with_label_values(&["Search"]).get());
+            assert_eq!(
+                10,
+                recorder.rows_returned.with_label_values(&["Search"]).get()
+            );
+            assert_eq!(
+                256,
+                recorder.bytes_sent.with_label_values(&["Search"]).get()
+            );
+            assert_eq!(
+                1,
+                recorder
+                    .errors
+                    .with_label_values(&["Search", "RateLimit"])
+                    .get()
+            );
+            assert_eq!(
+                2,
+                recorder
+                    .latency
+                    .with_label_values(&["Search"])
+                    .get_sample_count()
+            );
+        }
+    }
+}
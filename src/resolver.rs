@@ -0,0 +1,169 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+
+use tonic::transport::Uri;
+
+use crate::error::{Error, Result};
+
+pub type ResolveFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<String>>> + Send + 'a>>;
+
+/// Supplies the urls of the Milvus proxies a `Client` balances requests over.
+///
+/// The resolver is asked again on every health check round,
+/// so endpoints added or removed later are picked up without rebuilding the `Client`.
+pub trait Resolver: Send + Sync {
+    fn resolve(&self) -> ResolveFuture<'_>;
+}
+
+/// A fixed list of endpoints.
+#[derive(Debug, Clone)]
+pub struct StaticResolver {
+    endpoints: Vec<String>,
+}
+
+impl StaticResolver {
+    pub fn new<I>(endpoints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            endpoints: endpoints.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Resolver for StaticResolver {
+    fn resolve(&self) -> ResolveFuture<'_> {
+        Box::pin(async move { Ok(self.endpoints.clone()) })
+    }
+}
+
+/// Reads the endpoints from a file, one url per line,
+/// blank lines and lines starting with `#` are skipped.
+#[derive(Debug, Clone)]
+pub struct FileResolver {
+    path: PathBuf,
+}
+
+impl FileResolver {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Resolver for FileResolver {
+    fn resolve(&self) -> ResolveFuture<'_> {
+        Box::pin(async move {
+            let content = tokio::fs::read_to_string(&self.path).await?;
+            Ok(parse_endpoints(&content))
+        })
+    }
+}
+
+fn parse_endpoints(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Resolves a host name to all of its addresses, one endpoint per address.
+///
+/// The endpoints are addressed by IP, so with TLS the expected server name
+/// has to be set with [`ClientBuilder::tls_domain`](crate::client::ClientBuilder::tls_domain).
+#[derive(Debug, Clone)]
+pub struct DnsResolver {
+    scheme: String,
+    host: String,
+    port: u16,
+}
+
+impl DnsResolver {
+    /// Creates a resolver for `url`, e.g. `http://milvus-proxy.default.svc:19530`,
+    /// the port defaults to 19530.
+    pub fn new(url: &str) -> Result<Self> {
+        let uri: Uri = url
+            .parse()
+            .map_err(|err| Error::InvalidParameter("url".to_owned(), format!("{}", err)))?;
+        let host = uri
+            .host()
+            .ok_or_else(|| Error::InvalidParameter("url".to_owned(), "missing host".to_owned()))?;
+
+        Ok(Self {
+            scheme: uri.scheme_str().unwrap_or("http").to_owned(),
+            host: host.to_owned(),
+            port: uri.port_u16().unwrap_or(19530),
+        })
+    }
+}
+
+impl Resolver for DnsResolver {
+    fn resolve(&self) -> ResolveFuture<'_> {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((self.host.as_str(), self.port)).await?;
+            Ok(addrs
+                .map(|addr| format!("{}://{}", self.scheme, addr))
+                .collect())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_endpoints, DnsResolver, Resolver, StaticResolver};
+
+    #[test]
+    fn test_parse_endpoints() {
+        let content = "
+            # proxies
+            http://10.0.0.1:19530
+
+            http://10.0.0.2:19530
+        ";
+        assert_eq!(
+            vec!["http://10.0.0.1:19530", "http://10.0.0.2:19530"],
+            parse_endpoints(content)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_static_resolver() {
+        let resolver = StaticResolver::new(["http://a:19530", "http://b:19530"]);
+        assert_eq!(
+            vec!["http://a:19530", "http://b:19530"],
+            resolver.resolve().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dns_resolver() {
+        let resolver = DnsResolver::new("http://localhost").unwrap();
+        let endpoints = resolver.resolve().await.unwrap();
+        assert!(!endpoints.is_empty());
+        assert!(endpoints
+            .iter()
+            .all(|e| e.starts_with("http://") && e.ends_with(":19530")));
+
+        assert!(DnsResolver::new("not a url").is_err());
+    }
+}
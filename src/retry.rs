@@ -0,0 +1,183 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config;
+use crate::error::Error;
+
+/// Decides whether and when a failed request is sent again.
+///
+/// Requests that are not idempotent, such as insert, upsert and delete,
+/// are only retried if [`RetryPolicy::retry_non_idempotent`] is enabled.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+    jitter: f64,
+    retry_non_idempotent: bool,
+    predicate: Arc<dyn Fn(&Error) -> bool + Send + Sync>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: config::RETRY_MAX_ATTEMPTS,
+            base_backoff: config::RETRY_BASE_BACKOFF,
+            max_backoff: config::RETRY_MAX_BACKOFF,
+            jitter: 0.2,
+            retry_non_idempotent: false,
+            predicate: Arc::new(Error::is_retryable),
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_backoff", &self.base_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("jitter", &self.jitter)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A policy sending every request exactly once.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// The total number of attempts including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The delay before the first retry, it doubles for every following retry.
+    pub fn base_backoff(mut self, base_backoff: Duration) -> Self {
+        self.base_backoff = base_backoff;
+        self
+    }
+
+    /// The upper bound of the delay between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// The fraction of the delay that is randomized, clamped to `[0, 1]`.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Also retries requests that are not idempotent, which may apply them twice:
+    /// inserts, upserts and deletes, and the requests creating, dropping or renaming
+    /// collections, partitions, indexes, aliases and databases.
+    pub fn retry_non_idempotent(mut self, enable: bool) -> Self {
+        self.retry_non_idempotent = enable;
+        self
+    }
+
+    /// Replaces the predicate deciding which errors are retried,
+    /// by default it's [`Error::is_retryable`].
+    pub fn retry_if<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&Error) -> bool + Send + Sync + 'static,
+    {
+        self.predicate = Arc::new(predicate);
+        self
+    }
+
+    /// Whether to send again a request that failed with `err` in its `attempt`th attempt.
+    pub(crate) fn should_retry(&self, err: &Error, attempt: u32, idempotent: bool) -> bool {
+        attempt < self.max_attempts
+            && (idempotent || self.retry_non_idempotent)
+            && (self.predicate)(err)
+    }
+
+    /// The delay after the `attempt`th attempt failed.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_backoff
+            .saturating_mul(1 << exp)
+            .min(self.max_backoff);
+
+        backoff.mul_f64(1.0 - self.jitter * rand::random::<f64>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::RetryPolicy;
+    use crate::error::Error;
+    use crate::proto::common::ErrorCode;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::new()
+            .base_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(500))
+            .jitter(0.0);
+        assert_eq!(Duration::from_millis(100), policy.backoff(1));
+        assert_eq!(Duration::from_millis(200), policy.backoff(2));
+        assert_eq!(Duration::from_millis(400), policy.backoff(3));
+        assert_eq!(Duration::from_millis(500), policy.backoff(4));
+        assert_eq!(Duration::from_millis(500), policy.backoff(100));
+
+        let policy = policy.jitter(0.5);
+        for attempt in 1..10 {
+            let backoff = policy.backoff(attempt);
+            assert!(backoff >= Duration::from_millis(50) && backoff <= Duration::from_millis(500));
+        }
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::new().max_attempts(3);
+        let unavailable = Error::from(tonic::Status::unavailable("connection refused"));
+        let rate_limited = Error::RateLimited("rate limited".to_owned());
+        let not_exists =
+            Error::CollectionNotExists(ErrorCode::CollectionNotExists, "not exists".to_owned());
+
+        assert!(policy.should_retry(&unavailable, 1, true));
+        assert!(policy.should_retry(&rate_limited, 2, true));
+        assert!(!policy.should_retry(&rate_limited, 3, true));
+        assert!(!policy.should_retry(&not_exists, 1, true));
+        assert!(!policy.should_retry(&unavailable, 1, false));
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.should_retry(&unavailable, 1, false));
+
+        let policy = policy.retry_if(|err| err.code().is_some());
+        assert!(policy.should_retry(&not_exists, 1, true));
+        assert!(!policy.should_retry(&unavailable, 1, true));
+
+        assert!(!RetryPolicy::none().should_retry(&unavailable, 1, true));
+    }
+}
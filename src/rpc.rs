@@ -0,0 +1,437 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::client::Client;
+use crate::config::IDENTIFIER_HEADER;
+use crate::error::{Error, Result};
+use crate::proto::common::Status;
+use crate::proto::milvus::*;
+use crate::proto::schema::{
+    field_data, scalar_field, vector_field, FieldData, ScalarField, VectorField,
+};
+use crate::recorder::RpcEvent;
+use crate::service::ServiceClient;
+use crate::utils::status_to_result;
+
+pub(crate) trait RpcRequest: Clone + prost::Message {
+    /// The name of the request type, e.g. `InsertRequest`.
+    const NAME: &'static str;
+
+    /// Whether applying the request twice has the same effect as applying it once,
+    /// only idempotent requests are retried by default.
+    const IDEMPOTENT: bool = true;
+
+    /// The name of the RPC, e.g. `Insert`.
+    fn method() -> &'static str {
+        Self::NAME.trim_end_matches("Request")
+    }
+
+    fn collection_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// The number of rows written, or the number of vectors searched.
+    fn num_rows(&self) -> Option<u64> {
+        None
+    }
+}
+
+pub(crate) trait RpcResponse {
+    fn status(&self) -> Option<&Status>;
+
+    /// The number of rows returned by a query or search.
+    fn num_rows(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Implements `RpcRequest` for requests without a collection,
+/// the ones listed after `non_idempotent:` are not retried by default.
+macro_rules! impl_rpc_request {
+    ( $($t: ident),+ $(,)? ) => {
+        impl_rpc_request!(@impl true; $($t),+);
+    };
+    ( non_idempotent: $($t: ident),+ $(,)? ) => {
+        impl_rpc_request!(@impl false; $($t),+);
+    };
+    ( @impl $idempotent: literal; $($t: ident),+ ) => {$(
+        impl RpcRequest for $t {
+            const NAME: &'static str = stringify!($t);
+            const IDEMPOTENT: bool = $idempotent;
+        }
+    )*};
+}
+
+/// Implements `RpcRequest` for requests with a `collection_name`,
+/// the ones listed after `non_idempotent:` are not retried by default.
+macro_rules! impl_collection_rpc_request {
+    ( $($t: ident),+ $(,)? ) => {
+        impl_collection_rpc_request!(@impl true; $($t),+);
+    };
+    ( non_idempotent: $($t: ident),+ $(,)? ) => {
+        impl_collection_rpc_request!(@impl false; $($t),+);
+    };
+    ( @impl $idempotent: literal; $($t: ident),+ ) => {$(
+        impl RpcRequest for $t {
+            const NAME: &'static str = stringify!($t);
+            const IDEMPOTENT: bool = $idempotent;
+
+            fn collection_name(&self) -> Option<&str> {
+                Some(&self.collection_name)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_rpc_response {
+    ( $($t: ty),+ $(,)? ) => {$(
+        impl RpcResponse for $t {
+            fn status(&self) -> Option<&Status> {
+                self.status.as_ref()
+            }
+        }
+    )*};
+}
+
+impl RpcResponse for Status {
+    fn status(&self) -> Option<&Status> {
+        Some(self)
+    }
+}
+
+impl_rpc_request! {
+    ShowCollectionsRequest,
+    CheckHealthRequest,
+    GetVersionRequest,
+    GetComponentStatesRequest,
+    GetMetricsRequest,
+    ConnectRequest,
+    ListDatabasesRequest,
+    FlushRequest,
+    ManualCompactionRequest,
+    GetCompactionStateRequest,
+}
+
+// Applied twice, e.g. retried after the response was lost, these fail with
+// "already exists" or "not exists" although the first attempt succeeded
+impl_rpc_request! {
+    non_idempotent:
+    DropAliasRequest,
+    CreateDatabaseRequest,
+    DropDatabaseRequest,
+}
+
+impl_collection_rpc_request! {
+    AlterCollectionRequest,
+    HasCollectionRequest,
+    DescribeCollectionRequest,
+    GetCollectionStatisticsRequest,
+    LoadCollectionRequest,
+    ReleaseCollectionRequest,
+    GetLoadStateRequest,
+    GetLoadingProgressRequest,
+    HasPartitionRequest,
+    ShowPartitionsRequest,
+    GetPartitionStatisticsRequest,
+    DescribeIndexRequest,
+    AlterAliasRequest,
+    QueryRequest,
+}
+
+impl_collection_rpc_request! {
+    non_idempotent:
+    CreateCollectionRequest,
+    DropCollectionRequest,
+    CreatePartitionRequest,
+    DropPartitionRequest,
+    CreateIndexRequest,
+    DropIndexRequest,
+    CreateAliasRequest,
+}
+
+impl RpcRequest for RenameCollectionRequest {
+    const NAME: &'static str = "RenameCollectionRequest";
+    const IDEMPOTENT: bool = false;
+
+    fn collection_name(&self) -> Option<&str> {
+        Some(&self.old_name)
+    }
+}
+
+impl RpcRequest for SearchRequest {
+    const NAME: &'static str = "SearchRequest";
+
+    fn collection_name(&self) -> Option<&str> {
+        Some(&self.collection_name)
+    }
+
+    fn num_rows(&self) -> Option<u64> {
+        Some(self.nq as u64)
+    }
+}
+
+impl RpcRequest for InsertRequest {
+    const NAME: &'static str = "InsertRequest";
+    const IDEMPOTENT: bool = false;
+
+    fn collection_name(&self) -> Option<&str> {
+        Some(&self.collection_name)
+    }
+
+    fn num_rows(&self) -> Option<u64> {
+        Some(self.num_rows as u64)
+    }
+}
+
+impl RpcRequest for UpsertRequest {
+    const NAME: &'static str = "UpsertRequest";
+    const IDEMPOTENT: bool = false;
+
+    fn collection_name(&self) -> Option<&str> {
+        Some(&self.collection_name)
+    }
+
+    fn num_rows(&self) -> Option<u64> {
+        Some(self.num_rows as u64)
+    }
+}
+
+impl RpcRequest for DeleteRequest {
+    const NAME: &'static str = "DeleteRequest";
+    const IDEMPOTENT: bool = false;
+
+    fn collection_name(&self) -> Option<&str> {
+        Some(&self.collection_name)
+    }
+}
+
+impl_rpc_response! {
+    BoolResponse,
+    CheckHealthResponse,
+    GetVersionResponse,
+    ComponentStates,
+    GetMetricsResponse,
+    ConnectResponse,
+    DescribeCollectionResponse,
+    ShowCollectionsResponse,
+    GetCollectionStatisticsResponse,
+    GetLoadStateResponse,
+    GetLoadingProgressResponse,
+    ShowPartitionsResponse,
+    GetPartitionStatisticsResponse,
+    DescribeIndexResponse,
+    ListDatabasesResponse,
+    FlushResponse,
+    ManualCompactionResponse,
+    GetCompactionStateResponse,
+    MutationResult,
+}
+
+impl RpcResponse for QueryResults {
+    fn status(&self) -> Option<&Status> {
+        self.status.as_ref()
+    }
+
+    fn num_rows(&self) -> Option<u64> {
+        Some(self.fields_data.first().map_or(0, field_data_len) as u64)
+    }
+}
+
+impl RpcResponse for SearchResults {
+    fn status(&self) -> Option<&Status> {
+        self.status.as_ref()
+    }
+
+    fn num_rows(&self) -> Option<u64> {
+        Some(self.results.as_ref().map_or(0, |r| r.scores.len()) as u64)
+    }
+}
+
+/// The number of rows in a column, without decoding it.
+fn field_data_len(field: &FieldData) -> usize {
+    match &field.field {
+        Some(field_data::Field::Scalars(ScalarField { data: Some(data) })) => match data {
+            scalar_field::Data::BoolData(d) => d.data.len(),
+            scalar_field::Data::IntData(d) => d.data.len(),
+            scalar_field::Data::LongData(d) => d.data.len(),
+            scalar_field::Data::FloatData(d) => d.data.len(),
+            scalar_field::Data::DoubleData(d) => d.data.len(),
+            scalar_field::Data::StringData(d) => d.data.len(),
+            scalar_field::Data::BytesData(d) => d.data.len(),
+            scalar_field::Data::ArrayData(d) => d.data.len(),
+            scalar_field::Data::JsonData(d) => d.data.len(),
+        },
+        Some(field_data::Field::Vectors(VectorField {
+            dim,
+            data: Some(data),
+        })) if *dim > 0 => {
+            let dim = *dim as usize;
+            match data {
+                vector_field::Data::FloatVector(d) => d.data.len() / dim,
+                vector_field::Data::BinaryVector(d) => d.len() * 8 / dim,
+                vector_field::Data::Float16Vector(d) => d.len() / 2 / dim,
+                vector_field::Data::Bfloat16Vector(d) => d.len() / 2 / dim,
+            }
+        }
+        _ => 0,
+    }
+}
+
+impl Client {
+    /// Sends `request` through `call`, checks the status of the response
+    /// and retries according to the retry policy of the client.
+    ///
+    /// All attempts share one deadline, the timeout of the call options or of the client.
+    pub(crate) async fn invoke<Req, Resp, F, Fut>(&self, request: Req, call: F) -> Result<Resp>
+    where
+        Req: RpcRequest,
+        Resp: RpcResponse,
+        F: Fn(ServiceClient, tonic::Request<Req>) -> Fut,
+        Fut: Future<Output = std::result::Result<tonic::Response<Resp>, tonic::Status>>,
+    {
+        let _guard = self.service.begin()?;
+        let timeout = self.call_options.timeout.unwrap_or(self.timeout);
+        let start = Instant::now();
+        let deadline = start + timeout;
+
+        let call = with_deadline(timeout, async {
+            let mut attempt = 1;
+            loop {
+                let req = self.new_request(request.clone(), deadline)?;
+                let result = match call(self.service.client()?, req).await {
+                    Ok(resp) => {
+                        let resp = resp.into_inner();
+                        status_to_result(&resp.status().cloned()).map(|_| resp)
+                    }
+                    Err(status) => Err(Error::from(transport_status(status))),
+                };
+
+                match result {
+                    Err(err)
+                        if self
+                            .retry_policy
+                            .should_retry(&err, attempt, Req::IDEMPOTENT) =>
+                    {
+                        tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+                        attempt += 1;
+                    }
+                    result => return result,
+                }
+            }
+        });
+
+        #[cfg(feature = "tracing")]
+        let call = crate::trace::instrument(&request, &self.db_name, call);
+
+        let result = call.await;
+        if let Some(recorder) = &self.recorder.0 {
+            recorder.record(&RpcEvent {
+                method: Req::method(),
+                database: &self.db_name,
+                collection: request.collection_name(),
+                latency: start.elapsed(),
+                bytes_sent: request.encoded_len() as u64,
+                rows_returned: result.as_ref().ok().and_then(RpcResponse::num_rows),
+                error: result.as_ref().err(),
+            });
+        }
+
+        result
+    }
+
+    /// Bounds an operation made of several calls by the timeout of the call options, if any.
+    pub(crate) async fn with_call_deadline<T, Fut>(&self, fut: Fut) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        match self.call_options.timeout {
+            Some(timeout) => with_deadline(timeout, fut).await,
+            None => fut.await,
+        }
+    }
+
+    fn new_request<T>(&self, message: T, deadline: Instant) -> Result<tonic::Request<T>> {
+        let mut req = tonic::Request::new(message);
+        req.set_timeout(deadline.saturating_duration_since(Instant::now()));
+        self.call_options.apply(req.metadata_mut())?;
+        if let Some(identifier) = self.identifier {
+            req.metadata_mut()
+                .insert(IDENTIFIER_HEADER, identifier.into());
+        }
+        Ok(req)
+    }
+}
+
+/// Connection failures reach the client as `Unknown` statuses wrapping the transport error,
+/// they are reported as `Unavailable` instead, so that they are retried
+/// while the channel reconnects.
+fn transport_status(status: tonic::Status) -> tonic::Status {
+    let transport_err = std::error::Error::source(&status)
+        .filter(|err| status.code() == tonic::Code::Unknown && err.is::<tonic::transport::Error>());
+    let mut message = match transport_err {
+        Some(_) => status.message().to_owned(),
+        None => return status,
+    };
+
+    let mut source = transport_err.and_then(|err| err.source());
+    while let Some(err) = source {
+        message = format!("{}: {}", message, err);
+        source = err.source();
+    }
+    tonic::Status::unavailable(message)
+}
+
+pub(crate) async fn with_deadline<T, Fut>(timeout: Duration, fut: Fut) -> Result<T>
+where
+    Fut: Future<Output = Result<T>>,
+{
+    tokio::time::timeout(timeout, fut)
+        .await
+        .unwrap_or_else(|_| {
+            Err(Error::from(tonic::Status::deadline_exceeded(format!(
+                "deadline of {:?} exceeded",
+                timeout
+            ))))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::RpcRequest;
+    use crate::proto::milvus::{
+        CreateCollectionRequest, CreateDatabaseRequest, DescribeCollectionRequest,
+        DropCollectionRequest, InsertRequest, QueryRequest, RenameCollectionRequest,
+        ShowCollectionsRequest,
+    };
+
+    #[test]
+    fn test_idempotent_requests() {
+        assert!(ShowCollectionsRequest::IDEMPOTENT);
+        assert!(DescribeCollectionRequest::IDEMPOTENT);
+        assert!(QueryRequest::IDEMPOTENT);
+
+        assert!(!CreateCollectionRequest::IDEMPOTENT);
+        assert!(!DropCollectionRequest::IDEMPOTENT);
+        assert!(!RenameCollectionRequest::IDEMPOTENT);
+        assert!(!CreateDatabaseRequest::IDEMPOTENT);
+        assert!(!InsertRequest::IDEMPOTENT);
+    }
+}
@@ -26,7 +26,7 @@ use crate::proto::{
     schema::{self, DataType},
 };
 
-pub use crate::proto::schema::FieldData;
+pub use crate::proto::schema::{FieldData, ValueField};
 
 pub trait Schema {
     // fn name(&self) -> &str;
@@ -153,8 +153,13 @@ pub struct FieldSchema {
     pub is_primary: bool,
     pub auto_id: bool,
     pub chunk_size: usize,
-    pub dim: i64,        // only for BinaryVector and FloatVector
-    pub max_length: i32, // only for VarChar
+    pub dim: i64,               // only for BinaryVector and FloatVector
+    pub max_length: i32,        // only for VarChar
+    pub element_type: DataType, // only for Array
+    pub max_capacity: i32,      // only for Array
+    pub is_partition_key: bool,
+    pub is_dynamic: bool,
+    pub default_value: Option<ValueField>,
 }
 
 impl FieldSchema {
@@ -168,6 +173,11 @@ impl FieldSchema {
             chunk_size: 0,
             dim: 0,
             max_length: 0,
+            element_type: DataType::None,
+            max_capacity: 0,
+            is_partition_key: false,
+            is_dynamic: false,
+            default_value: None,
         }
     }
 }
@@ -178,16 +188,22 @@ impl Default for FieldSchema {
     }
 }
 
+/// Parses the type param of the given key, `None` if it's missing or invalid.
+fn type_param<T: std::str::FromStr>(params: &[KeyValuePair], key: &str) -> Option<T> {
+    params
+        .iter()
+        .find(|kv| kv.key == key)
+        .and_then(|kv| kv.value.parse().ok())
+}
+
 impl From<schema::FieldSchema> for FieldSchema {
     fn from(fld: schema::FieldSchema) -> Self {
-        let dim: i64 = fld
-            .type_params
-            .iter()
-            .find(|k| &k.key == "dim")
-            .and_then(|x| x.value.parse().ok())
-            .unwrap_or(1);
+        let dim: i64 = type_param(&fld.type_params, "dim").unwrap_or(1);
+        let max_length: i32 = type_param(&fld.type_params, "max_length").unwrap_or(0);
+        let max_capacity: i32 = type_param(&fld.type_params, "max_capacity").unwrap_or(0);
 
-        let dtype = DataType::from_i32(fld.data_type).unwrap();
+        let dtype = fld.data_type();
+        let element_type = fld.element_type();
 
         FieldSchema {
             name: fld.name,
@@ -195,13 +211,17 @@ impl From<schema::FieldSchema> for FieldSchema {
             dtype,
             is_primary: fld.is_primary_key,
             auto_id: fld.auto_id,
-            max_length: 0,
-            chunk_size: (dim
-                * match dtype {
-                    DataType::BinaryVector => dim / 8,
-                    _ => dim,
-                }) as _,
+            max_length,
+            chunk_size: (match dtype {
+                DataType::BinaryVector => dim / 8,
+                _ => dim,
+            }) as _,
             dim,
+            element_type,
+            max_capacity,
+            is_partition_key: fld.is_partition_key,
+            is_dynamic: fld.is_dynamic,
+            default_value: fld.default_value,
         }
     }
 }
@@ -217,6 +237,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -230,6 +251,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -243,6 +265,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -256,6 +279,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -269,6 +293,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -282,6 +307,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -300,6 +326,7 @@ impl FieldSchema {
             max_length,
             chunk_size: 1,
             dim: 1,
+            ..Self::const_default()
         }
     }
 
@@ -313,6 +340,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -326,6 +354,7 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
@@ -339,15 +368,16 @@ impl FieldSchema {
             chunk_size: 1,
             dim: 1,
             max_length: 0,
+            ..Self::const_default()
         }
     }
 
-    pub fn new_varchar(name: &str, description: &str, max_length: i32) -> Self {
+    pub fn new_varchar(name: &str, description: &str, max_length: i32) -> Result<Self> {
         if max_length <= 0 {
-            panic!("max_length should be positive");
+            return Err(Error::InvalidMaxLength(name.to_owned(), max_length).into());
         }
 
-        Self {
+        Ok(Self {
             name: name.to_owned(),
             description: description.to_owned(),
             dtype: DataType::VarChar,
@@ -356,15 +386,16 @@ impl FieldSchema {
             auto_id: false,
             chunk_size: 1,
             dim: 1,
-        }
+            ..Self::const_default()
+        })
     }
 
-    pub fn new_binary_vector(name: &str, description: &str, dim: i64) -> Self {
+    pub fn new_binary_vector(name: &str, description: &str, dim: i64) -> Result<Self> {
         if dim <= 0 {
-            panic!("dim should be positive");
+            return Err(Error::InvalidDimension(name.to_owned(), dim).into());
         }
 
-        Self {
+        Ok(Self {
             name: name.to_owned(),
             description: description.to_owned(),
             dtype: DataType::BinaryVector,
@@ -373,15 +404,16 @@ impl FieldSchema {
             is_primary: false,
             auto_id: false,
             max_length: 0,
-        }
+            ..Self::const_default()
+        })
     }
 
-    pub fn new_float_vector(name: &str, description: &str, dim: i64) -> Self {
+    pub fn new_float_vector(name: &str, description: &str, dim: i64) -> Result<Self> {
         if dim <= 0 {
-            panic!("dim should be positive");
+            return Err(Error::InvalidDimension(name.to_owned(), dim).into());
         }
 
-        Self {
+        Ok(Self {
             name: name.to_owned(),
             description: description.to_owned(),
             dtype: DataType::FloatVector,
@@ -390,13 +422,14 @@ impl FieldSchema {
             is_primary: false,
             auto_id: false,
             max_length: 0,
-        }
+            ..Self::const_default()
+        })
     }
 }
 
 impl From<FieldSchema> for schema::FieldSchema {
     fn from(fld: FieldSchema) -> schema::FieldSchema {
-        let params = match fld.dtype {
+        let mut params = match fld.dtype {
             DataType::BinaryVector | DataType::FloatVector => vec![KeyValuePair {
                 key: "dim".to_string(),
                 value: fld.dim.to_string(),
@@ -407,6 +440,18 @@ impl From<FieldSchema> for schema::FieldSchema {
             }],
             _ => Vec::new(),
         };
+        if fld.dtype == DataType::Array {
+            params.push(KeyValuePair {
+                key: "max_capacity".to_string(),
+                value: fld.max_capacity.to_string(),
+            });
+            if fld.element_type == DataType::VarChar {
+                params.push(KeyValuePair {
+                    key: "max_length".to_string(),
+                    value: fld.max_length.to_string(),
+                });
+            }
+        }
 
         schema::FieldSchema {
             field_id: 0,
@@ -418,10 +463,10 @@ impl From<FieldSchema> for schema::FieldSchema {
             index_params: Vec::new(),
             auto_id: fld.auto_id,
             state: FieldState::FieldCreated as _,
-            element_type: 0,
-            default_value: None,
-            is_dynamic: false,
-            is_partition_key: false,
+            element_type: fld.element_type as _,
+            default_value: fld.default_value,
+            is_dynamic: fld.is_dynamic,
+            is_partition_key: fld.is_partition_key,
         }
     }
 }
@@ -439,6 +484,18 @@ impl CollectionSchema {
         &self.name
     }
 
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn fields(&self) -> &[FieldSchema] {
+        &self.fields
+    }
+
+    pub fn enable_dynamic_field(&self) -> bool {
+        self.enable_dynamic_field
+    }
+
     #[inline]
     pub fn auto_id(&self) -> bool {
         self.fields.iter().any(|x| x.auto_id)
@@ -484,7 +541,13 @@ impl From<CollectionSchema> for schema::CollectionSchema {
             name: col.name.to_string(),
             auto_id: col.auto_id(),
             description: col.description,
-            fields: col.fields.into_iter().map(Into::into).collect(),
+            // The dynamic field is added by the server, a described schema must not send it back
+            fields: col
+                .fields
+                .into_iter()
+                .filter(|f| !f.is_dynamic)
+                .map(Into::into)
+                .collect(),
             enable_dynamic_field: col.enable_dynamic_field,
         }
     }
@@ -629,4 +692,10 @@ pub enum Error {
 
     #[error("field {0:?} must be a vector field")]
     NotVectorField(String),
+
+    #[error("dimension of field {0:?} must be positive, got {1:?}")]
+    InvalidDimension(String, i64),
+
+    #[error("max length of field {0:?} must be positive, got {1:?}")]
+    InvalidMaxLength(String, i32),
 }
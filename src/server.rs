@@ -0,0 +1,210 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::proto::{
+    self,
+    common::{MsgBase, MsgType},
+    milvus::ConnectRequest,
+};
+
+pub(crate) const SDK_TYPE: &str = "Rust";
+pub(crate) const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// What the server reported about itself when the client connected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServerInfo {
+    /// The release the server was built from, e.g. `v2.3.4`.
+    pub build_tags: String,
+    pub build_time: String,
+    pub git_commit: String,
+    pub go_version: String,
+    /// `STANDALONE` or `CLUSTER`.
+    pub deploy_mode: String,
+    pub reserved: HashMap<String, String>,
+}
+
+impl ServerInfo {
+    /// The server version, parsed from the build tags, e.g. `(2, 3, 4)` for `v2.3.4-rc1`.
+    pub fn version(&self) -> Option<(u64, u64, u64)> {
+        let version = self.build_tags.trim_start_matches('v');
+        let version = version.split(['-', '+']).next()?;
+        let mut parts = version.split('.').map(|p| p.parse::<u64>());
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) => Some((major, minor, patch)),
+            _ => None,
+        }
+    }
+}
+
+impl From<proto::common::ServerInfo> for ServerInfo {
+    fn from(value: proto::common::ServerInfo) -> Self {
+        Self {
+            build_tags: value.build_tags,
+            build_time: value.build_time,
+            git_commit: value.git_commit,
+            go_version: value.go_version,
+            deploy_mode: value.deploy_mode,
+            reserved: value.reserved,
+        }
+    }
+}
+
+/// What the client reports about itself when connecting.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClientInfo {
+    pub user: String,
+    pub reserved: HashMap<String, String>,
+}
+
+impl From<ClientInfo> for proto::common::ClientInfo {
+    fn from(value: ClientInfo) -> Self {
+        Self {
+            sdk_type: SDK_TYPE.to_owned(),
+            sdk_version: SDK_VERSION.to_owned(),
+            local_time: format_time(SystemTime::now()),
+            user: value.user,
+            host: hostname(),
+            reserved: value.reserved,
+        }
+    }
+}
+
+impl Client {
+    /// Introduces the client to the server, then keeps the server info and
+    /// sends the identifier it returned with every following request.
+    ///
+    /// Servers without the Connect RPC are tolerated, `server_info` stays `None`.
+    pub(crate) async fn connect(&mut self, info: ClientInfo) -> Result<()> {
+        let res = self
+            .invoke(
+                ConnectRequest {
+                    base: Some(MsgBase::new(MsgType::Connect)),
+                    client_info: Some(info.into()),
+                },
+                |mut client, req| async move { client.connect(req).await },
+            )
+            .await;
+
+        let res = match res {
+            Ok(res) => res,
+            Err(Error::Grpc(status)) if status.code() == tonic::Code::Unimplemented => {
+                return Ok(())
+            }
+            Err(err) => return Err(err),
+        };
+
+        self.server_info = res.server_info.map(|info| Arc::new(info.into()));
+        self.identifier = Some(res.identifier);
+        Ok(())
+    }
+
+    /// Returns what the server reported about itself when the client connected,
+    /// `None` if the server doesn't support the handshake.
+    pub fn server_info(&self) -> Option<&ServerInfo> {
+        self.server_info.as_deref()
+    }
+
+    /// Returns the identifier the server assigned to this client when it connected.
+    pub fn identifier(&self) -> Option<i64> {
+        self.identifier
+    }
+}
+
+/// Formats `time` as `YYYY-MM-DD hh:mm:ss` in UTC.
+fn format_time(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let (days, secs) = ((secs / 86400) as i64, secs % 86400);
+
+    // Converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// The name of the host, looked up once on a best-effort basis: from the environment,
+/// then the kernel on Linux. Empty if none of them has it.
+fn hostname() -> String {
+    static HOSTNAME: OnceLock<String> = OnceLock::new();
+
+    HOSTNAME
+        .get_or_init(|| {
+            let lookups: [fn() -> Option<String>; 3] = [
+                || std::env::var("HOSTNAME").ok(),
+                || std::env::var("COMPUTERNAME").ok(),
+                || std::fs::read_to_string("/proc/sys/kernel/hostname").ok(),
+            ];
+
+            lookups
+                .iter()
+                .filter_map(|lookup| lookup())
+                .map(|name| name.trim().to_owned())
+                .find(|name| !name.is_empty())
+                .unwrap_or_default()
+        })
+        .clone()
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{format_time, ServerInfo};
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!("1970-01-01 00:00:00", format_time(UNIX_EPOCH));
+        assert_eq!(
+            "2024-02-29 13:05:09",
+            format_time(UNIX_EPOCH + Duration::from_secs(1709211909))
+        );
+    }
+
+    #[test]
+    fn test_server_version() {
+        let info = |tags: &str| ServerInfo {
+            build_tags: tags.to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(Some((2, 3, 4)), info("v2.3.4").version());
+        assert_eq!(Some((2, 4, 0)), info("v2.4.0-rc.1").version());
+        assert_eq!(None, info("").version());
+    }
+}
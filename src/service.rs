@@ -0,0 +1,158 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::Notify;
+use tonic::body::BoxBody;
+use tonic::codec::CompressionEncoding;
+use tonic::codegen::{http, Body, Bytes, StdError};
+use tower::util::BoxCloneService;
+use tower::{BoxError, Service, ServiceExt};
+
+use crate::error::{Error, Result};
+use crate::proto::milvus::milvus_service_client::MilvusServiceClient;
+
+/// The type-erased service requests go through: the user layers,
+/// then the `AuthInterceptor`, then the channel.
+pub type MilvusService = BoxCloneService<http::Request<BoxBody>, http::Response<BoxBody>, BoxError>;
+
+pub(crate) type ServiceClient = MilvusServiceClient<MilvusService>;
+
+/// Wraps a service into a layer of the stack.
+pub(crate) type BoxLayer = Arc<dyn Fn(MilvusService) -> MilvusService + Send + Sync>;
+
+/// Erases the type of `service`, boxing its response body and error.
+pub(crate) fn boxed<S, B>(service: S) -> MilvusService
+where
+    S: Service<http::Request<BoxBody>, Response = http::Response<B>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<StdError>,
+{
+    BoxCloneService::new(
+        service
+            .map_response(|res: http::Response<B>| {
+                res.map(|body| {
+                    body.map_err(|err| tonic::Status::from_error(err.into()))
+                        .boxed_unsync()
+                })
+            })
+            .map_err(Into::into),
+    )
+}
+
+/// Message size limits and compression, applied to the `MilvusServiceClient` of every call.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CodecOptions {
+    pub max_decoding_message_size: Option<usize>,
+    pub max_encoding_message_size: Option<usize>,
+    pub send_compressed: Option<CompressionEncoding>,
+    pub accept_compressed: Option<CompressionEncoding>,
+}
+
+/// The service shared by a `Client` and all of its clones.
+///
+/// A boxed service is `Send` but not `Sync`, so it's kept behind a mutex
+/// that is only held to clone it for each call. It's taken out once the client is closed.
+#[derive(Clone)]
+pub(crate) struct SharedService {
+    service: Arc<Mutex<Option<MilvusService>>>,
+    codec: CodecOptions,
+    in_flight: Arc<InFlight>,
+}
+
+/// Counts the calls in progress, so that closing the client can wait for them.
+#[derive(Default)]
+struct InFlight {
+    closed: AtomicBool,
+    count: AtomicUsize,
+    drained: Notify,
+}
+
+/// Marks a call in progress until dropped.
+pub(crate) struct CallGuard(Arc<InFlight>);
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        if self.0.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.drained.notify_waiters();
+        }
+    }
+}
+
+impl SharedService {
+    pub fn new(service: MilvusService, codec: CodecOptions) -> Self {
+        Self {
+            service: Arc::new(Mutex::new(Some(service))),
+            codec,
+            in_flight: Arc::default(),
+        }
+    }
+
+    /// Registers a call in progress, fails if the client is closed.
+    pub fn begin(&self) -> Result<CallGuard> {
+        // Counted before checking the flag, so `close` can't miss a call that passed the check
+        self.in_flight.count.fetch_add(1, Ordering::SeqCst);
+        let guard = CallGuard(self.in_flight.clone());
+        if self.in_flight.closed.load(Ordering::SeqCst) {
+            return Err(Error::Closed);
+        }
+        Ok(guard)
+    }
+
+    /// Rejects new calls, waits for the ones in progress to complete, retries included,
+    /// then drops the service so that the connections are closed.
+    pub async fn close(&self) {
+        self.in_flight.closed.store(true, Ordering::SeqCst);
+        loop {
+            let drained = self.in_flight.drained.notified();
+            if self.in_flight.count.load(Ordering::SeqCst) == 0 {
+                break;
+            }
+            drained.await;
+        }
+        self.service.lock().unwrap().take();
+    }
+
+    pub fn client(&self) -> Result<ServiceClient> {
+        let service = self.service.lock().unwrap().clone().ok_or(Error::Closed)?;
+        let mut client = MilvusServiceClient::new(service);
+        if let Some(limit) = self.codec.max_decoding_message_size {
+            client = client.max_decoding_message_size(limit);
+        }
+        if let Some(limit) = self.codec.max_encoding_message_size {
+            client = client.max_encoding_message_size(limit);
+        }
+        if let Some(encoding) = self.codec.send_compressed {
+            client = client.send_compressed(encoding);
+        }
+        if let Some(encoding) = self.codec.accept_compressed {
+            client = client.accept_compressed(encoding);
+        }
+        Ok(client)
+    }
+}
+
+impl std::fmt::Debug for SharedService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedService")
+            .field("codec", &self.codec)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,89 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spans around every RPC, enabled by the `tracing` feature.
+
+use std::future::Future;
+use std::time::Instant;
+
+use opentelemetry::propagation::Injector;
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
+use tracing::field::Empty;
+use tracing::{Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::error::Result;
+use crate::rpc::RpcRequest;
+
+/// Runs `fut` in a span named after the RPC of `request`,
+/// recording the latency and the error code once it completes.
+pub(crate) fn instrument<'a, Req, T, Fut>(
+    request: &Req,
+    db_name: &str,
+    fut: Fut,
+) -> impl Future<Output = Result<T>> + 'a
+where
+    Req: RpcRequest,
+    Fut: Future<Output = Result<T>> + 'a,
+{
+    let span = tracing::info_span!(
+        "milvus.rpc",
+        otel.name = Req::method(),
+        otel.kind = "client",
+        rpc.system = "grpc",
+        rpc.method = Req::method(),
+        db.name = db_name,
+        collection = request.collection_name(),
+        rows = request.num_rows(),
+        latency_ms = Empty,
+        error_code = Empty,
+    );
+
+    async move {
+        let start = Instant::now();
+        let result = fut.instrument(span.clone()).await;
+
+        span.record("latency_ms", start.elapsed().as_secs_f64() * 1000.0);
+        if let Err(err) = &result {
+            span.record("error_code", err.code_name().as_str());
+            tracing::debug!(parent: &span, error = %err, "rpc failed");
+        }
+
+        result
+    }
+}
+
+/// Injects the trace context of the current span into the metadata of a request,
+/// with the propagator registered in [`opentelemetry::global`].
+pub(crate) fn inject_context(metadata: &mut MetadataMap) {
+    let context = Span::current().context();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut MetadataInjector(metadata))
+    });
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (
+            key.parse::<AsciiMetadataKey>(),
+            value.parse::<AsciiMetadataValue>(),
+        ) {
+            self.0.insert(key, value);
+        }
+    }
+}
@@ -19,19 +19,63 @@ use crate::{
     proto::common::{ErrorCode, Status},
 };
 
+/// Succeeds only if neither the deprecated `error_code` nor the `code` of Milvus 2.3+
+/// reports an error, as newer servers may only set the latter.
 pub fn status_to_result(status: &Option<Status>) -> Result<(), Error> {
     let status = status
         .clone()
         .ok_or(Error::Unexpected("no status".to_owned()))?;
 
-    match ErrorCode::from_i32(status.error_code) {
-        Some(i) => match i {
-            ErrorCode::Success => Ok(()),
-            _ => Err(Error::from(status)),
-        },
-        None => Err(Error::Unexpected(format!(
-            "unknown error code {}",
-            status.error_code
-        ))),
+    #[allow(deprecated)]
+    let error_code = status.error_code;
+    match ErrorCode::from_i32(error_code) {
+        Some(ErrorCode::Success) if status.code == 0 => Ok(()),
+        _ => Err(Error::from(status)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::status_to_result;
+    use crate::error::Error;
+    use crate::proto::common::{ErrorCode, Status};
+
+    #[test]
+    fn test_status_to_result() {
+        assert!(status_to_result(&Some(Status::default())).is_ok());
+        assert!(matches!(status_to_result(&None), Err(Error::Unexpected(_))));
+
+        #[allow(deprecated)]
+        let legacy = Status {
+            error_code: ErrorCode::RateLimit as i32,
+            ..Default::default()
+        };
+        assert!(matches!(
+            status_to_result(&Some(legacy)),
+            Err(Error::RateLimited(_))
+        ));
+
+        // Failures reported only through the code of Milvus 2.3+
+        let not_found = Status {
+            code: 100,
+            reason: "collection not found".to_owned(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            status_to_result(&Some(not_found)),
+            Err(Error::CollectionNotExists(
+                ErrorCode::CollectionNotExists,
+                _
+            ))
+        ));
+
+        let unknown = Status {
+            code: 65535,
+            reason: "something new".to_owned(),
+            ..Default::default()
+        };
+        let err = status_to_result(&Some(unknown)).unwrap_err();
+        assert_eq!(Some(ErrorCode::UnexpectedError), err.code());
+        assert!(err.to_string().contains("65535"));
     }
 }
@@ -246,9 +246,11 @@ impl ValueVec {
     }
 }
 
-impl From<Field> for ValueVec {
-    fn from(f: Field) -> Self {
-        match f {
+impl TryFrom<Field> for ValueVec {
+    type Error = crate::error::Error;
+
+    fn try_from(f: Field) -> Result<Self, Self::Error> {
+        Ok(match f {
             Field::Scalars(s) => match s.data {
                 Some(x) => match x {
                     ScalarData::BoolData(v) => Self::Bool(v.data),
@@ -259,7 +261,11 @@ impl From<Field> for ValueVec {
                     ScalarData::StringData(v) => Self::String(v.data),
                     ScalarData::JsonData(v) => Self::Json(v.data),
                     ScalarData::ArrayData(v) => Self::Array(v.data),
-                    ScalarData::BytesData(_) => unimplemented!(), // Self::Bytes(v.data),
+                    ScalarData::BytesData(_) => {
+                        return Err(crate::error::Error::Unexpected(
+                            "bytes data is not supported".to_owned(),
+                        ))
+                    }
                 },
                 None => Self::None,
             },
@@ -273,7 +279,7 @@ impl From<Field> for ValueVec {
                 },
                 None => Self::None,
             },
-        }
+        })
     }
 }
 
@@ -393,4 +399,22 @@ mod test {
         assert!(b.is_ok());
         assert_eq!(v, b.unwrap());
     }
+
+    #[test]
+    fn test_try_from_field() {
+        use crate::proto::schema::{
+            field_data::Field, scalar_field::Data, BytesArray, LongArray, ScalarField,
+        };
+
+        let field = Field::Scalars(ScalarField {
+            data: Some(Data::LongData(LongArray { data: vec![1, 2] })),
+        });
+        assert!(matches!(ValueVec::try_from(field), Ok(ValueVec::Long(v)) if v == vec![1, 2]));
+
+        // Bytes are not supported yet, which must not panic
+        let field = Field::Scalars(ScalarField {
+            data: Some(Data::BytesData(BytesArray { data: vec![] })),
+        });
+        assert!(ValueVec::try_from(field).is_err());
+    }
 }
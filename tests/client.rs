@@ -48,6 +48,157 @@ async fn create_client_wrong_fmt() -> Result<()> {
     }
 }
 
+#[tokio::test]
+async fn server_info() -> Result<()> {
+    let client = ClientBuilder::new(URL)
+        .client_metadata("app", "tests")
+        .build()
+        .await?;
+    let info = client.server_info().expect("server info after connect");
+    assert!(
+        info.version().is_some(),
+        "unexpected tags {}",
+        info.build_tags
+    );
+    assert!(client.identifier().is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn health() -> Result<()> {
+    let client = Client::new(URL).await?;
+    let report = client.check_health().await?;
+    assert!(report.is_healthy, "unhealthy: {:?}", report.reasons);
+
+    assert!(client.get_version().await?.starts_with('v'));
+
+    let states = client.get_component_states().await?;
+    assert!(states.is_healthy(), "{:?}", states);
+    Ok(())
+}
+
+#[tokio::test]
+async fn system_info_metrics() -> Result<()> {
+    let client = Client::new(URL).await?;
+    match client
+        .get_metrics(milvus::metrics::MetricsRequestType::SystemInfo)
+        .await?
+    {
+        milvus::metrics::Metrics::SystemInfo(topology) => {
+            assert!(topology
+                .nodes_info
+                .iter()
+                .any(|node| node.infos.role == "proxy"));
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn custom_interceptor() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let client = ClientBuilder::new(URL)
+        .interceptor(move |mut req: tonic::Request<()>| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            req.metadata_mut()
+                .insert("x-tenant", "tests".parse().unwrap());
+            Ok(req)
+        })
+        .build()
+        .await?;
+
+    let before = calls.load(Ordering::SeqCst);
+    client.has_collection("qwerty").await?;
+    assert_eq!(before + 1, calls.load(Ordering::SeqCst));
+    Ok(())
+}
+
+#[tokio::test]
+async fn transport_options() -> Result<()> {
+    let client = ClientBuilder::new(URL)
+        .connect_timeout(std::time::Duration::from_secs(5))
+        .tcp_nodelay(true)
+        .keep_alive_interval(std::time::Duration::from_secs(30))
+        .keep_alive_timeout(std::time::Duration::from_secs(10))
+        .keep_alive_while_idle(true)
+        .max_decoding_message_size(64 * 1024 * 1024)
+        .max_encoding_message_size(64 * 1024 * 1024)
+        .send_compressed(CompressionEncoding::Gzip)
+        .accept_compressed(CompressionEncoding::Gzip)
+        .build()
+        .await?;
+    client.has_collection("qwerty").await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_client_with_uri_params() -> Result<()> {
+    let client = ClientBuilder::new(format!("{}/default?timeout=5s", URL))
+        .build()
+        .await?;
+    client.has_collection("qwerty").await?;
+
+    match ClientBuilder::new(format!("{}?retries=3", URL))
+        .build()
+        .await
+    {
+        Ok(_) => panic!("Should fail due to the unknown query parameter."),
+        Err(_) => Ok(()),
+    }
+}
+
+#[tokio::test]
+async fn create_client_lazy() -> Result<()> {
+    use milvus::error::Error;
+    use std::time::Duration;
+
+    // Building doesn't need the server to be up
+    let client = ClientBuilder::new("http://localhost:9999")
+        .connect_lazy(true)
+        .build()
+        .await?;
+    assert!(client.wait_ready(Duration::from_secs(1)).await.is_err());
+
+    let client = ClientBuilder::new(URL).connect_lazy(true).build().await?;
+    client.wait_ready(Duration::from_secs(10)).await?;
+    client.has_collection("qwerty").await?;
+
+    client.close().await;
+    assert!(matches!(
+        client.has_collection("qwerty").await,
+        Err(Error::Closed)
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_client_endpoints() -> Result<()> {
+    let client = ClientBuilder::endpoints([URL, "http://localhost:9999"])
+        .build()
+        .await?;
+    // The unreachable endpoint is ejected, so every request goes to the healthy one
+    for _ in 0..4 {
+        client.has_collection("qwerty").await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_client_endpoints_all_down() -> Result<()> {
+    match ClientBuilder::endpoints(["http://localhost:9998", "http://localhost:9999"])
+        .build()
+        .await
+    {
+        Ok(_) => panic!("Should fail due to no healthy endpoint."),
+        Err(_) => Result::<()>::Ok(()),
+    }
+}
+
 #[tokio::test]
 async fn has_collection() -> Result<()> {
     const NAME: &str = "qwerty";
@@ -83,7 +234,7 @@ async fn create_has_drop_collection() -> Result<()> {
         client.drop_collection(NAME).await?;
     }
 
-    let collection = client
+    client
         .create_collection(
             schema,
             Some(CreateCollectionOptions::with_consistency_level(
@@ -14,15 +14,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use milvus::client::ConsistencyLevel;
-use milvus::collection::{Collection, ParamValue};
+use futures_util::TryStreamExt;
+use milvus::client::{Client, ConsistencyLevel};
+use milvus::collection::{Collection, CollectionProperties, ParamValue};
 use milvus::data::FieldColumn;
 use milvus::error::Result;
 use milvus::index::{IndexParams, IndexType, MetricType};
 use milvus::mutate::InsertOptions;
-use milvus::options::LoadOptions;
+use milvus::options::{ListCollectionsOptions, LoadOptions};
 use milvus::query::{QueryOptions, SearchOptions};
 use std::collections::HashMap;
+use std::time::Duration;
 
 mod common;
 use common::*;
@@ -37,6 +39,111 @@ async fn manual_compaction_empty_collection() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn rename_collection() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+    let new_name = format!("{}_renamed", schema.name());
+
+    // Caches the collection under its old name
+    client.describe_collection(schema.name()).await?;
+    client
+        .rename_collection(schema.name(), &new_name, None)
+        .await?;
+
+    assert!(!client.has_collection(schema.name()).await?);
+    assert!(client.has_collection(&new_name).await?);
+    assert_eq!(new_name, client.describe_collection(&new_name).await?.name);
+
+    client.drop_collection(&new_name).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_collection_recreated_elsewhere() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+    let cached = client.refresh_collection(schema.name()).await?;
+
+    // Recreated by another client, so the cache of the first one is stale
+    let other = Client::new(URL).await?;
+    other.drop_collection(schema.name()).await?;
+    other.create_collection(schema.clone(), None).await?;
+
+    let refreshed = client.refresh_collection(schema.name()).await?;
+    assert_ne!(cached.id, refreshed.id);
+    assert_eq!(0, client.manual_compaction(schema.name()).await?.plan_count);
+
+    client.drop_collection(schema.name()).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn compaction_refreshes_recreated_collection() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+    let cached = client.refresh_collection(schema.name()).await?;
+
+    let other = Client::new(URL).await?;
+    other.drop_collection(schema.name()).await?;
+    other.create_collection(schema.clone(), None).await?;
+
+    // Sent with the id of the dropped collection first, then retried with the new one
+    assert_eq!(0, client.manual_compaction(schema.name()).await?.plan_count);
+    let recreated = other.describe_collection(schema.name()).await?;
+    assert_ne!(cached.id, recreated.id);
+
+    client.drop_collection(schema.name()).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn alter_collection_properties() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+
+    client
+        .alter_collection(
+            schema.name(),
+            CollectionProperties::new()
+                .ttl_seconds(3600)
+                .property("collection.autocompaction.enabled", "false"),
+        )
+        .await?;
+
+    let properties = client.describe_collection(schema.name()).await?.properties;
+    assert_eq!(Some(3600), properties.ttl_seconds);
+    assert_eq!(
+        "false",
+        properties.extra["collection.autocompaction.enabled"]
+    );
+
+    client.drop_collection(schema.name()).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn describe_collection_round_trip() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+
+    let collection = client.describe_collection(schema.name()).await?;
+    assert_eq!(schema.fields().len(), collection.schema.fields().len());
+    assert_eq!(
+        DEFAULT_DIM,
+        collection.schema.get_field(DEFAULT_VEC_FIELD).unwrap().dim
+    );
+    assert!(!collection.virtual_channel_names.is_empty());
+    assert!(collection.created_utc_timestamp > 0);
+
+    // Recreates the collection from its description
+    client.drop_collection(schema.name()).await?;
+    client
+        .create_collection(collection.schema.clone(), Some(collection.create_options()))
+        .await?;
+    let recreated = client.describe_collection(schema.name()).await?;
+    assert_eq!(collection.num_shards, recreated.num_shards);
+    assert_eq!(collection.consistency_level, recreated.consistency_level);
+
+    client.drop_collection(schema.name()).await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn collection_upsert() -> Result<()> {
     let (client, schema) = create_test_collection(false).await?;
@@ -71,6 +178,87 @@ async fn collection_upsert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn collection_count() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+
+    let embed_data = gen_random_f32_vector(DEFAULT_DIM * 2000);
+    let embed_column = FieldColumn::new(schema.get_field(DEFAULT_VEC_FIELD).unwrap(), embed_data);
+    client
+        .insert(schema.name(), vec![embed_column], None)
+        .await?;
+    client.flush(schema.name()).await?;
+    assert_eq!(
+        2000,
+        client.get_collection_stats(schema.name()).await?.row_count
+    );
+
+    create_test_index(&client, schema.name()).await?;
+    client
+        .load_collection(schema.name(), Some(LoadOptions::default()))
+        .await?;
+
+    let options = QueryOptions::with_consistency_level(ConsistencyLevel::Strong);
+    assert_eq!(2000, client.count(schema.name(), "", &options).await?);
+    assert_eq!(0, client.count(schema.name(), "id < 0", &options).await?);
+
+    client.drop_collection(schema.name()).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_collections_loaded_only() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+
+    let all = client.list_collections_detailed(None).await?;
+    let info = all.iter().find(|c| c.name == schema.name()).unwrap();
+    assert_eq!(client.describe_collection(schema.name()).await?.id, info.id);
+
+    let loaded_only = Some(ListCollectionsOptions::with_loaded_only(true));
+    let loaded = client
+        .list_collections_detailed(loaded_only.clone())
+        .await?;
+    assert!(loaded.iter().all(|c| c.name != schema.name()));
+
+    create_test_index(&client, schema.name()).await?;
+    client
+        .load_collection(schema.name(), Some(LoadOptions::default()))
+        .await?;
+
+    let loaded = client.list_collections_detailed(loaded_only).await?;
+    let info = loaded.iter().find(|c| c.name == schema.name()).unwrap();
+    assert_eq!(Some(100), info.in_memory_percentage);
+
+    client.drop_collection(schema.name()).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_collection_async_progress() -> Result<()> {
+    let (client, schema) = create_test_collection(true).await?;
+
+    create_test_index(&client, schema.name()).await?;
+
+    let handle = client.load_collection_async(schema.name(), None).await?;
+    let progress: Vec<i64> = handle.progress_stream().try_collect().await?;
+    assert_eq!(Some(&100), progress.last());
+    assert!(progress.windows(2).all(|w| w[0] <= w[1]));
+
+    handle.wait(Some(Duration::from_secs(60))).await?;
+    assert_eq!(100, handle.progress().await?);
+
+    // Already loaded, so the deadline is not reached
+    client
+        .load_collection(
+            schema.name(),
+            Some(LoadOptions::default().timeout(Duration::from_secs(60))),
+        )
+        .await?;
+
+    client.drop_collection(schema.name()).await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn collection_basic() -> Result<()> {
     let (client, schema) = create_test_collection(true).await?;
@@ -1,8 +1,10 @@
 use milvus::client::*;
 use milvus::error::Result;
+use milvus::index::{IndexParams, IndexType, MetricType};
 use milvus::options::CreateCollectionOptions;
 use milvus::schema::{CollectionSchema, CollectionSchemaBuilder, FieldSchema};
 use rand::Rng;
+use std::collections::HashMap;
 
 pub const DEFAULT_DIM: i64 = 128;
 pub const DEFAULT_VEC_FIELD: &str = "feature";
@@ -19,7 +21,7 @@ pub async fn create_test_collection(autoid: bool) -> Result<(Client, CollectionS
             DEFAULT_VEC_FIELD,
             "",
             DEFAULT_DIM,
-        ))
+        )?)
         .build()?;
     if client.has_collection(&collection_name).await? {
         client.drop_collection(&collection_name).await?;
@@ -35,6 +37,20 @@ pub async fn create_test_collection(autoid: bool) -> Result<(Client, CollectionS
     Ok((client, schema))
 }
 
+/// Creates an IVF_FLAT index on the vector field, which loading the collection requires.
+#[allow(dead_code)] // only used by some of the test binaries
+pub async fn create_test_index(client: &Client, collection_name: &str) -> Result<()> {
+    let index_params = IndexParams::new(
+        DEFAULT_INDEX_NAME.to_owned(),
+        IndexType::IvfFlat,
+        MetricType::L2,
+        HashMap::from([("nlist".to_owned(), "32".to_owned())]),
+    );
+    client
+        .create_index(collection_name, DEFAULT_VEC_FIELD, index_params)
+        .await
+}
+
 pub fn gen_random_name() -> String {
     format!(
         "r{}",
@@ -0,0 +1,62 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use milvus::client::*;
+use milvus::error::Result;
+use milvus::schema::*;
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn create_list_drop_database() -> Result<()> {
+    let db_name = format!("test_db_{}", gen_random_name());
+    let client = Client::new(URL).await?;
+
+    client.create_database(&db_name).await?;
+    assert!(client.list_databases().await?.contains(&db_name));
+
+    client.drop_database(&db_name).await?;
+    assert!(!client.list_databases().await?.contains(&db_name));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn same_collection_name_in_databases() -> Result<()> {
+    let db_name = format!("test_db_{}", gen_random_name());
+    let collection_name = format!("test_collection_{}", gen_random_name());
+    let client = Client::new(URL).await?;
+    client.create_database(&db_name).await?;
+    let db_client = client.with_database(&db_name);
+
+    let schema = CollectionSchemaBuilder::new(&collection_name, "")
+        .add_field(FieldSchema::new_primary_int64("id", "", true))
+        .add_field(FieldSchema::new_float_vector(
+            DEFAULT_VEC_FIELD,
+            "",
+            DEFAULT_DIM,
+        )?)
+        .build()?;
+    db_client.create_collection(schema, None).await?;
+
+    assert!(db_client.has_collection(&collection_name).await?);
+    assert!(!client.has_collection(&collection_name).await?);
+
+    db_client.drop_collection(&collection_name).await?;
+    client.drop_database(&db_name).await?;
+    Ok(())
+}
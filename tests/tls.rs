@@ -0,0 +1,186 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// These tests run against a TLS terminating stand-in server rather than Milvus,
+// it completes the TLS and HTTP/2 handshakes then answers every call with UNIMPLEMENTED.
+// The certificates under tests/certs are signed by tests/certs/ca.pem,
+// the server one is issued for "localhost".
+
+use std::convert::Infallible;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use hyper::service::service_fn;
+use hyper::{Body, Request, Response};
+use milvus::client::ClientBuilder;
+use milvus::error::{Error, Result};
+use tokio::net::TcpListener;
+use tokio_rustls::rustls::{self, server::AllowAnyAuthenticatedClient, RootCertStore};
+use tokio_rustls::TlsAcceptor;
+
+const CERTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/certs");
+
+fn cert_path(name: &str) -> String {
+    format!("{}/{}", CERTS, name)
+}
+
+fn load_certs(name: &str) -> Vec<rustls::Certificate> {
+    let mut reader = BufReader::new(File::open(cert_path(name)).unwrap());
+    rustls_pemfile::certs(&mut reader)
+        .unwrap()
+        .into_iter()
+        .map(rustls::Certificate)
+        .collect()
+}
+
+fn load_key(name: &str) -> rustls::PrivateKey {
+    let mut reader = BufReader::new(File::open(cert_path(name)).unwrap());
+    rustls::PrivateKey(
+        rustls_pemfile::pkcs8_private_keys(&mut reader)
+            .unwrap()
+            .remove(0),
+    )
+}
+
+async fn unimplemented(_: Request<Body>) -> std::result::Result<Response<Body>, Infallible> {
+    Ok(Response::builder()
+        .header("content-type", "application/grpc")
+        .header("grpc-status", "12")
+        .body(Body::empty())
+        .unwrap())
+}
+
+async fn spawn_server(require_client_cert: bool) -> SocketAddr {
+    let builder = rustls::ServerConfig::builder().with_safe_defaults();
+    let builder = if require_client_cert {
+        let mut roots = RootCertStore::empty();
+        for cert in load_certs("ca.pem") {
+            roots.add(&cert).unwrap();
+        }
+        builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots))
+    } else {
+        builder.with_no_client_auth()
+    };
+    let mut config = builder
+        .with_single_cert(load_certs("server.pem"), load_key("server.key"))
+        .unwrap();
+    config.alpn_protocols = vec![b"h2".to_vec()];
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                if let Ok(stream) = acceptor.accept(stream).await {
+                    let _ = hyper::server::conn::Http::new()
+                        .http2_only(true)
+                        .serve_connection(stream, service_fn(unimplemented))
+                        .await;
+                }
+            });
+        }
+    });
+
+    addr
+}
+
+// A call reaching the stand-in server fails with UNIMPLEMENTED,
+// anything else means the connection could not be established.
+async fn reaches_server(builder: ClientBuilder<String>) -> bool {
+    let client = match builder.build().await {
+        Ok(client) => client,
+        Err(_) => return false,
+    };
+
+    match client.has_collection("tls").await {
+        Err(Error::Grpc(status)) => status.code() == tonic::Code::Unimplemented,
+        _ => false,
+    }
+}
+
+#[tokio::test]
+async fn tls_with_private_ca() -> Result<()> {
+    let addr = spawn_server(false).await;
+    let builder = ClientBuilder::new(format!("https://{}", addr))
+        .ca_certificate_file(cert_path("ca.pem"))
+        .tls_domain("localhost");
+    assert!(reaches_server(builder).await);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_with_ca_bytes() -> Result<()> {
+    let addr = spawn_server(false).await;
+    let builder = ClientBuilder::new(format!("https://{}", addr))
+        .ca_certificate(std::fs::read(cert_path("ca.pem"))?)
+        .tls_domain("localhost");
+    assert!(reaches_server(builder).await);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_untrusted_server() -> Result<()> {
+    let addr = spawn_server(false).await;
+    let builder = ClientBuilder::new(format!("https://{}", addr)).tls_domain("localhost");
+    assert!(!reaches_server(builder).await);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_wrong_domain() -> Result<()> {
+    let addr = spawn_server(false).await;
+    let builder = ClientBuilder::new(format!("https://{}", addr))
+        .ca_certificate_file(cert_path("ca.pem"))
+        .tls_domain("milvus.example.com");
+    assert!(!reaches_server(builder).await);
+    Ok(())
+}
+
+#[tokio::test]
+async fn mtls_with_client_identity() -> Result<()> {
+    let addr = spawn_server(true).await;
+    let builder = ClientBuilder::new(format!("https://{}", addr))
+        .ca_certificate_file(cert_path("ca.pem"))
+        .client_identity_files(cert_path("client.pem"), cert_path("client.key"))
+        .tls_domain("localhost");
+    assert!(reaches_server(builder).await);
+    Ok(())
+}
+
+#[tokio::test]
+async fn mtls_without_client_identity() -> Result<()> {
+    let addr = spawn_server(true).await;
+    let builder = ClientBuilder::new(format!("https://{}", addr))
+        .ca_certificate_file(cert_path("ca.pem"))
+        .tls_domain("localhost");
+    assert!(!reaches_server(builder).await);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_requires_https() -> Result<()> {
+    let result = ClientBuilder::new("http://localhost:19530".to_owned())
+        .ca_certificate_file(cert_path("ca.pem"))
+        .build()
+        .await;
+    assert!(matches!(result, Err(Error::InvalidParameter(..))));
+    Ok(())
+}